@@ -0,0 +1,45 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Benchmarks of the per-ray transform to object space, scalar against SIMD.
+// Run with: cargo bench --features wide
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ruxel::geometry::simd::SimdMatrix4;
+use ruxel::geometry::transform::Transform;
+use ruxel::prelude::*;
+
+fn bench_transform(c: &mut Criterion) {
+    let m = Matrix4::from_rotation_y(0.5)
+        .scale(2.0, 1.0, 3.0)
+        .translate(1.0, 2.0, 3.0);
+    let inverse = m.inverse();
+    let simd_inverse = SimdMatrix4::from(inverse);
+    let transform = Transform::new(m);
+    let ray = Ray::new(Point3::new(1.0, 2.0, -5.0), Vector3::new(0.0, 0.5, 1.0));
+
+    c.bench_function("ray_transform_scalar", |b| {
+        b.iter(|| Ray::transform(black_box(ray), *black_box(&inverse)))
+    });
+    c.bench_function("ray_transform_simd", |b| {
+        b.iter(|| black_box(&simd_inverse).transform_ray(black_box(ray)))
+    });
+    c.bench_function("transform_to_object_space", |b| {
+        b.iter(|| black_box(&transform).to_object_space(black_box(ray)))
+    });
+
+    let mut sphere = Sphere::new(ShapeId(1));
+    sphere.set_transform(m);
+    c.bench_function("sphere_intersect_ref", |b| {
+        b.iter(|| black_box(&sphere).intersect_ref(black_box(ray)).len())
+    });
+}
+
+criterion_group!(benches, bench_transform);
+criterion_main!(benches);
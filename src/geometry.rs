@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/**
+/*!
 The geometry module implements the functionality for Points, Vectors, Matrices, and their transformations
 */
 
@@ -16,6 +16,27 @@ pub const EPSILON: f64 = 0.0001;
 // Bring into scope the f64 math constants in the standard library
 use std::f64::consts::PI;
 
+use num::NumCast;
+
+/// Converts the f64 result of an intermediate computation back to the scalar type 'P'.
+///
+/// Geometry computations are carried out in f64, so integer scalars never overflow or
+/// truncate halfway through. For integer scalars the result is rounded to the nearest
+/// integer, with halves rounded away from zero. Returns None when the value is not finite
+/// or does not fit in 'P', instead of panicking.
+pub fn scalar_from_f64<P: NumCast>(value: f64) -> Option<P> {
+    // Integer types cannot represent 0.5, which tells them apart from float types.
+    let is_integer = P::from(0.5).and_then(|p: P| p.to_f64()) != Some(0.5);
+    if is_integer {
+        P::from(value.round())
+    } else {
+        P::from(value)
+    }
+}
+
+/// Numeric trait gathering the bounds of the geometry scalar types.
+pub mod scalar;
+
 /// Provides data structures, methods and traits for Matrix4 computations.
 pub mod matrix;
 
@@ -27,3 +48,16 @@ pub mod ray;
 
 /// Data structures and methods for Intersections computations.
 pub mod intersection;
+
+/// Data structures and methods to compose nested transformations.
+pub mod transform;
+
+/// Barycentric coordinates and interpolation of triangle attributes.
+pub mod barycentric;
+
+/// Reflection and refraction functions for Vectors at a surface.
+pub mod optics;
+
+/// SIMD-backed storage and operations for f64 Vectors, Points and Matrices.
+#[cfg(feature = "wide")]
+pub mod simd;
@@ -0,0 +1,60 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Barycentric coordinates of a Point relative to a triangle, and interpolation of the
+attributes of the triangle vertices -normals, UV coordinates- by those coordinates.
+*/
+use std::ops::{Add, Mul};
+
+use num::Float;
+
+use super::scalar::Scalar;
+use super::vector::*;
+
+// Barycentric Unit Tests
+#[cfg(test)]
+mod tests;
+
+/**
+Returns the barycentric coordinates (u, v, w) of 'p' relative to the triangle 'a', 'b', 'c',
+so that p = u * a + v * b + w * c and u + v + w = 1.
+A Point outside of the plane of the triangle is projected onto it. All the coordinates lie
+in [0, 1] only when the Point is inside the triangle.
+Returns None if the triangle is degenerate: its vertices are collinear.
+*/
+pub fn barycentric<P>(p: Point3<P>, a: Point3<P>, b: Point3<P>, c: Point3<P>) -> Option<(P, P, P)>
+where
+    P: Scalar + Float,
+{
+    let (v0, v1, v2) = (b - a, c - a, p - a);
+    let d00 = Vector3::dot(v0, v0);
+    let d01 = Vector3::dot(v0, v1);
+    let d11 = Vector3::dot(v1, v1);
+    let d20 = Vector3::dot(v2, v0);
+    let d21 = Vector3::dot(v2, v1);
+
+    let denom = d00 * d11 - d01 * d01;
+    if denom.abs() <= P::epsilon() * d00 * d11 {
+        return None;
+    }
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some((P::one() - v - w, v, w))
+}
+
+/// Interpolates the attributes of the three vertices of a triangle, e.g. normals or UV
+/// coordinates, with the barycentric coordinates returned by [`barycentric`].
+pub fn interpolate<P, T>(coords: (P, P, P), a: T, b: T, c: T) -> T
+where
+    P: Copy,
+    T: Add<Output = T> + Mul<P, Output = T>,
+{
+    let (u, v, w) = coords;
+    a * u + b * v + c * w
+}
@@ -0,0 +1,53 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the barycentric functions.
+
+use super::*;
+
+#[test]
+// Barycentric coordinates of the vertices, an inner point and an outer point
+fn ut_barycentric_coordinates() {
+    let (a, b, c) = (
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+    );
+    assert_eq!(barycentric(a, a, b, c), Some((1.0, 0.0, 0.0)));
+    assert_eq!(barycentric(c, a, b, c), Some((0.0, 0.0, 1.0)));
+
+    let (u, v, w) = barycentric(Point3::new(0.0, 0.5, 0.0), a, b, c).unwrap();
+    assert_eq!((u, v, w), (0.5, 0.25, 0.25));
+
+    let (u, _, _) = barycentric(Point3::new(0.0, 2.0, 0.0), a, b, c).unwrap();
+    assert!(u > 1.0);
+
+    let line = Point3::new(2.0, 0.0, 0.0);
+    assert_eq!(barycentric(a, b, c, line), None);
+}
+
+#[test]
+// Normals and UV coordinates are interpolated across a triangle
+fn ut_barycentric_interpolate() {
+    let coords = (0.5, 0.25, 0.25);
+    let n = interpolate(
+        coords,
+        Vector3::y_coord(1.0),
+        Vector3::x_coord(-1.0),
+        Vector3::x_coord(1.0),
+    );
+    assert_eq!(n, Vector3::new(0.0, 0.5, 0.0));
+
+    let uv = interpolate(
+        coords,
+        Vector2::new(0.5, 1.0),
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+    );
+    assert_eq!(uv, Vector2::new(0.5, 0.5));
+}
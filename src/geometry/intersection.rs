@@ -6,16 +6,18 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+/*!
+ Data structures and methods for Intersections computations.
+*/
+
 use crate::shapes::{sphere::*, Shape};
-use num::{Num, NumCast};
+use num::{Float, Num};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 // Bring geometry module constants into scope
-use super::{matrix::*, ray::*, vector::*, EPSILON};
-
-/**
- Data structures and methods for Intersections computations.
-*/
+use super::{matrix::*, ray::*, scalar::Scalar, vector::*, EPSILON};
 
 // Unit tests for Intersections.
 #[cfg(test)]
@@ -64,19 +66,17 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.t == other.t
     }
-
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
-    }
 }
 
 /// Finds and returns the 'hit' -visible intersection- in a collection.
+/// The objects only need to be Copy, so intersections holding references to Shapes work too.
+#[cfg_attr(feature = "profiling", profiling::function)]
 pub fn hit<P, S>(xs: IntxnVec<P, S>) -> Option<Intxn<P, S>>
 where
-    P: Num + NumCast + Copy + PartialEq + PartialOrd + Display,
-    S: Shape<P> + Copy,
+    P: Scalar,
+    S: Copy,
 {
-    let mut min = xs[0].t;
+    let mut min = xs.first()?.t;
     let mut id = 0;
     let mut flag = false;
     for (idx, ixn) in xs.iter().enumerate() {
@@ -92,13 +92,72 @@ where
         return None;
     }
 
-    return Some(xs[id]);
+    Some(xs[id])
+}
+
+/**
+Sorts a collection of intersections by increasing 't' and merges the intersections whose
+'t' values lie within 'epsilon' of each other, so the n1/n2 container algorithm sees a
+consistent order at tangent and touching surfaces.
+
+The 't' values of merged intersections are snapped to the lowest one. Within a group of
+merged intersections, the Shapes being exited come first, then the Shapes only grazed
+(entered and exited in the same group), and then the Shapes being entered. Ties are broken
+by 'key', e.g. the [`ShapeId`](crate::shapes::ShapeId), so the order is deterministic.
+*/
+pub fn merge_intersections<P, S, K>(xs: &mut IntxnVec<P, S>, epsilon: P, key: impl Fn(&S) -> K)
+where
+    P: Scalar + Float,
+    K: Ord + Copy,
+{
+    xs.sort_by(|a, b| {
+        a.t.partial_cmp(&b.t)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| key(&a.object).cmp(&key(&b.object)))
+    });
+
+    // Number of intersections seen so far for every Shape: odd while the Ray is inside it.
+    let mut seen: BTreeMap<K, usize> = BTreeMap::new();
+    let mut start = 0;
+    while start < xs.len() {
+        let t0 = xs[start].t;
+        let mut end = start + 1;
+        while end < xs.len() && xs[end].t - t0 <= epsilon {
+            end += 1;
+        }
+
+        let mut in_group: BTreeMap<K, usize> = BTreeMap::new();
+        for ix in &xs[start..end] {
+            *in_group.entry(key(&ix.object)).or_insert(0) += 1;
+        }
+        let rank = |k: &K| {
+            if seen.get(k).copied().unwrap_or(0) % 2 == 1 {
+                0
+            } else if in_group[k] > 1 {
+                1
+            } else {
+                2
+            }
+        };
+        xs[start..end].sort_by(|a, b| {
+            let (ka, kb) = (key(&a.object), key(&b.object));
+            rank(&ka).cmp(&rank(&kb)).then_with(|| ka.cmp(&kb))
+        });
+        for ix in &mut xs[start..end] {
+            ix.t = t0;
+        }
+
+        for (k, n) in in_group {
+            *seen.entry(k).or_insert(0) += n;
+        }
+        start = end;
+    }
 }
 
 /// Common set of operations for Intersections
 pub trait Intersection<P, S>
 where
-    P: Num + Copy + Display,
+    P: Scalar,
     S: Shape<P> + Copy,
 {
     /// Returns an intersection with a 't' distance between a Ray and a Shape
@@ -107,7 +166,7 @@ where
 
 impl<P, S> Intersection<P, S> for Intxn<P, S>
 where
-    P: Num + Copy + Display,
+    P: Scalar,
     S: Shape<P> + Copy,
 {
     fn intersection(t: P, object: S) -> Self {
@@ -11,12 +11,12 @@
 use super::*;
 use crate::geometry::ray::*;
 use crate::intersections;
-use crate::shapes::sphere::*;
+use crate::shapes::{sphere::*, ShapeId};
 
 #[test]
 // Encapsulation of 't' and object
 fn ut_intersection_encapsulation() {
-    let s = Sphere::new(1);
+    let s = Sphere::new(ShapeId(1));
     let i = Intxn::intersection(3.5, s);
     assert_eq!(i.t, 3.5);
     assert_eq!(i.object.get_name(), "sphere");
@@ -25,21 +25,21 @@ fn ut_intersection_encapsulation() {
 #[test]
 // Aggregating intersections.
 fn ut_intersection_aggregating() {
-    let s = Sphere::new(1);
-    let i1 = Intxn::intersection(1, s);
-    let i2 = Intxn::intersection(2, s);
+    let s = Sphere::new(ShapeId(1));
+    let i1 = Intxn::intersection(1.0, s);
+    let i2 = Intxn::intersection(2.0, s);
     let xs = intersections![i1, i2];
     assert_eq!(xs.len(), 2);
-    assert_eq!(xs[0].t, 1);
-    assert_eq!(xs[1].t, 2);
+    assert_eq!(xs[0].t, 1.0);
+    assert_eq!(xs[1].t, 2.0);
 }
 
 #[test]
 // hit all intxns have positive t
 fn ut_intersection_hit_positive_t() {
-    let s = Sphere::new(1);
-    let i1 = Intxn::intersection(1, s);
-    let i2 = Intxn::intersection(2, s);
+    let s = Sphere::new(ShapeId(1));
+    let i1 = Intxn::intersection(1.0, s);
+    let i2 = Intxn::intersection(2.0, s);
     let xs = intersections![i2, i1];
     let i = hit(xs);
     assert_eq!(i, Some(i1));
@@ -48,9 +48,9 @@ fn ut_intersection_hit_positive_t() {
 #[test]
 // hit some intxns have negative t
 fn ut_intersection_hit_negative_t() {
-    let s = Sphere::new(1);
-    let i1 = Intxn::intersection(-1, s);
-    let i2 = Intxn::intersection(1, s);
+    let s = Sphere::new(ShapeId(1));
+    let i1 = Intxn::intersection(-1.0, s);
+    let i2 = Intxn::intersection(1.0, s);
     let xs = intersections![i2, i1];
     let i = hit(xs);
     assert_eq!(i, Some(i2));
@@ -59,9 +59,9 @@ fn ut_intersection_hit_negative_t() {
 #[test]
 // hit all intxns have negative t
 fn ut_intersection_hit_negative_all_t() {
-    let s = Sphere::new(1);
-    let i1 = Intxn::intersection(-2, s);
-    let i2 = Intxn::intersection(-1, s);
+    let s = Sphere::new(ShapeId(1));
+    let i1 = Intxn::intersection(-2.0, s);
+    let i2 = Intxn::intersection(-1.0, s);
     let xs = intersections![i2, i1];
     let i = hit(xs);
     assert_eq!(i, None);
@@ -70,12 +70,61 @@ fn ut_intersection_hit_negative_all_t() {
 #[test]
 // hit is lowest non-negative inx
 fn ut_intersection_hit_lowest_t() {
-    let s = Sphere::new(1);
-    let i1 = Intxn::intersection(5, s);
-    let i2 = Intxn::intersection(7, s);
-    let i3 = Intxn::intersection(-3, s);
-    let i4 = Intxn::intersection(2, s);
+    let s = Sphere::new(ShapeId(1));
+    let i1 = Intxn::intersection(5.0, s);
+    let i2 = Intxn::intersection(7.0, s);
+    let i3 = Intxn::intersection(-3.0, s);
+    let i4 = Intxn::intersection(2.0, s);
     let xs = intersections![i2, i1, i3, i4];
     let i = hit(xs);
     assert_eq!(i, Some(i4));
 }
+
+#[test]
+// Touching glass spheres: the exit of the first comes before the entry of the second
+fn ut_intersection_merge_touching_spheres() {
+    let a = Sphere::new(ShapeId(2));
+    let b = Sphere::new(ShapeId(1));
+    let mut xs = intersections![
+        Intxn::intersection(8.0, b),
+        Intxn::intersection(6.0, a),
+        Intxn::intersection(5.99999, b),
+        Intxn::intersection(4.0, a),
+    ];
+    merge_intersections(&mut xs, EPSILON, |s| s.get_id());
+    let order: Vec<(f64, ShapeId)> = xs.iter().map(|i| (i.t, i.object.get_id())).collect();
+    assert_eq!(
+        order,
+        vec![
+            (4.0, ShapeId(2)),
+            (5.99999, ShapeId(2)),
+            (5.99999, ShapeId(1)),
+            (8.0, ShapeId(1)),
+        ]
+    );
+}
+
+#[test]
+// A glass sphere grazed where another one is exited keeps its pair of hits together
+fn ut_intersection_merge_tangent_sphere() {
+    let a = Sphere::new(ShapeId(1));
+    let b = Sphere::new(ShapeId(2));
+    let c = Sphere::new(ShapeId(3));
+    let mut xs = intersections![
+        Intxn::intersection(6.0, c),
+        Intxn::intersection(6.0, b),
+        Intxn::intersection(6.0, a),
+        Intxn::intersection(6.0, b),
+        Intxn::intersection(4.0, a),
+        Intxn::intersection(7.0, c),
+    ];
+    merge_intersections(&mut xs, EPSILON, |s| s.get_id());
+    let ids: Vec<u32> = xs.iter().map(|i| i.object.get_id().0).collect();
+    assert_eq!(ids, vec![1, 1, 2, 2, 3, 3]);
+
+    // Without an epsilon, distinct 't' values are only sorted.
+    let mut xs = intersections![Intxn::intersection(6.00001, a), Intxn::intersection(6.0, b)];
+    merge_intersections(&mut xs, 0.0, |s| s.get_id());
+    assert_eq!(xs[0].object.get_id(), ShapeId(2));
+    assert_eq!(xs[1].t, 6.00001);
+}
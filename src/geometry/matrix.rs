@@ -12,16 +12,17 @@
 #[cfg(test)]
 mod tests;
 
-use num::{Num, NumCast};
+use num::{Float, Num, NumCast};
 use std::cmp::{Eq, PartialEq};
 use std::fmt::Display;
-use std::ops::{Mul, MulAssign, Neg};
+use std::ops::{Index, IndexMut, Mul, MulAssign};
 
-// Bring Vector module constants into scope
+// Bring Scalar and Vector module constants into scope
+use super::scalar::Scalar;
 use super::vector::*;
 
 // Bring Geometry module constants into scope
-use super::EPSILON;
+use super::{scalar_from_f64, EPSILON};
 
 /**
 Matrix 4x4 with generic data type.
@@ -52,6 +53,35 @@ pub enum Matrix4Index {
     Four,
 }
 
+impl Matrix4Index {
+    // Position of the Row or Column in the matrix data.
+    fn position(&self) -> usize {
+        match self {
+            Matrix4Index::One => 0,
+            Matrix4Index::Two => 1,
+            Matrix4Index::Three => 2,
+            Matrix4Index::Four => 3,
+        }
+    }
+}
+
+/// Errors returned by the fallible Matrix4 operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The matrix has a determinant of '0' and cannot be inverted, e.g. a zero scale.
+    Singular,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixError::Singular => f.write_str("Matrix cannot be inversed: it is singular"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
 /**
 Matrix 4x4 with generic data.
 The data resides in the 'm' component of the structure.
@@ -59,16 +89,18 @@ To access the data:
 matrix.m[0][0] = 12.5;
 */
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix4<P> {
     m: Matrix4Data<P>,
 }
 
 /**
-Matrix3 generic structure.
-It is only used in this module to calculate Matrix4 determinat and cofactor.
+Matrix 3x3 with generic data.
+It is used to calculate Matrix4 determinant and cofactor, and as the normal matrix that
+transforms surface normals: the inverse-transpose of the upper-left 3x3 of a Matrix4.
 */
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct Matrix3<P> {
+pub struct Matrix3<P> {
     m: [[P; 3]; 3],
 }
 
@@ -119,6 +151,35 @@ where
     }
 }
 
+impl<P> Display for Matrix3<P>
+where
+    P: Copy + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = "".to_string();
+        for row in self.m {
+            s.push_str(&format!(
+                "[{:^8.5}, {:^8.5}, {:^8.5}]\n",
+                &row[0], &row[1], &row[2]
+            ));
+        }
+        f.write_str(&s)
+    }
+}
+
+impl<P> PartialEq for Matrix3<P>
+where
+    P: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| (a.to_f64().unwrap() - b.to_f64().unwrap()).abs() < EPSILON)
+    }
+}
+
 impl<P> PartialEq for Matrix4<P>
 where
     P: Copy + Num + NumCast,
@@ -142,10 +203,6 @@ where
         }
         flag
     }
-
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
-    }
 }
 
 // Implementation Associated Functions with Crate visibility to compute
@@ -153,7 +210,7 @@ where
 
 impl<P> Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     pub(crate) fn cofactor(self, row_del: usize, col_del: usize) -> P {
         if (row_del + col_del) % 2 == 0 {
@@ -163,6 +220,10 @@ where
         }
     }
 
+    pub(crate) fn data(self) -> Matrix4Data<P> {
+        self.m
+    }
+
     pub(crate) fn determinant(self) -> P {
         let mut det = num::zero();
         for col in 0..4 {
@@ -204,15 +265,62 @@ pub trait Matrix4Ops<P> {
     /// Returns the row of the matrix based on an user-defined index.
     fn get_row(&self, index: Matrix4Index) -> Matrix4Row<P>;
 
-    /// Returns the row of the matrix based on an user-defined index.
+    /// Returns the column of the matrix based on an user-defined index.
     fn get_col(&self, index: Matrix4Index) -> Matrix4Col<P>;
 
+    /// Replaces the row of the matrix at an user-defined index.
+    fn set_row(&mut self, index: Matrix4Index, row: Matrix4Row<P>);
+
+    /// Replaces the column of the matrix at an user-defined index.
+    fn set_col(&mut self, index: Matrix4Index, col: Matrix4Col<P>);
+
+    /// Returns the rotation matrix of the (yaw, pitch, roll) Euler angles in radians:
+    /// a roll around the Z axis, then a pitch around the X axis, then a yaw around the Y axis.
+    fn from_euler(yaw: P, pitch: P, roll: P) -> Self;
+
+    /// Returns the (yaw, pitch, roll) Euler angles in radians of the rotation part of a
+    /// matrix built as in from_euler. Pitch is in [-PI/2, PI/2]; at +/-PI/2 (gimbal lock)
+    /// the roll is folded into the yaw and returned as '0'.
+    fn to_euler(&self) -> (P, P, P);
+
+    /// Returns a new translation matrix, without mutating an existing one.
+    fn from_translation(x: P, y: P, z: P) -> Self;
+
+    /// Returns a new scaling matrix, without mutating an existing one.
+    fn from_scale(x: P, y: P, z: P) -> Self;
+
+    /// Returns a new rotation matrix around the X axis, without mutating an existing one.
+    fn from_rotation_x(radians: P) -> Self;
+
+    /// Returns a new rotation matrix around the Y axis, without mutating an existing one.
+    fn from_rotation_y(radians: P) -> Self;
+
+    /// Returns a new rotation matrix around the Z axis, without mutating an existing one.
+    fn from_rotation_z(radians: P) -> Self;
+
+    /// Returns a new rotation matrix of 'radians' around 'axis', which does not need to be
+    /// normalized. Returns the identity matrix if 'axis' has a magnitude of '0'.
+    fn from_axis_angle(axis: Vector3<P>, radians: P) -> Self;
+
+    /// Returns a new shearing matrix, without mutating an existing one.
+    fn from_shear(xy: P, xz: P, yx: P, yz: P, zx: P, zy: P) -> Self;
+
     /// Returns a new identity matrix.
+    #[deprecated(
+        note = "use the Matrix4::IDENTITY associated const, or Matrix4Ops::from_scale(num::one(), num::one(), num::one()) in generic code"
+    )]
     fn identity() -> Self;
 
     /// Returns the inverse of a matrix.
+    /// Panics if the matrix is singular; see try_inverse.
     fn inverse(self) -> Self;
 
+    /// Returns the inverse of a matrix, or [`MatrixError::Singular`] if its determinant
+    /// is '0', e.g. for a degenerate transform with a zero scale.
+    fn try_inverse(self) -> Result<Self, MatrixError>
+    where
+        Self: Sized;
+
     /// Returns the new matrix with the data provided by the user.
     /// If no data is provided the function returns the matrix filled with '0'.
     fn new(data: Option<Matrix4Data<P>>) -> Self;
@@ -220,6 +328,12 @@ pub trait Matrix4Ops<P> {
     /// Returns a new matrix filled with '1'.
     fn one() -> Self;
 
+    /// Returns a copy of the matrix with the rotation part (upper 3x3) re-orthonormalized.
+    /// Useful to remove the drift accumulated after composing many rotations.
+    fn orthonormalized(self) -> Self
+    where
+        P: Float;
+
     /// Returns rotation matrix around the X axis
     fn rotate_x(&mut self, radians: P) -> Self;
 
@@ -244,16 +358,116 @@ pub trait Matrix4Ops<P> {
     /// Reverts the matrix into an idenitity matrix.
     fn to_identity(&mut self) -> Self;
 
+    /// Returns the view transformation -'look at' matrix- of an eye at 'from' looking at 'to',
+    /// with 'up' approximating the upwards direction. It moves the world so the eye sits at
+    /// the origin looking towards -Z, with 'up' along +Y, which orients the camera.
+    fn view_transform(from: Point3<P>, to: Point3<P>, up: Vector3<P>) -> Self
+    where
+        P: Float;
+
     /// Returns a new matrix filled with '0'.
+    #[deprecated(
+        note = "use the Matrix4::ZERO associated const, or Matrix4Ops::new(None) in generic code"
+    )]
     fn zero() -> Self;
 
     /// Returns a new matrix in f64 format.
     fn mat_to_f64(self) -> Matrix4<f64>;
+
+    /// Returns the upper-left 3x3 matrix: the linear part of the transformation, without
+    /// the translation.
+    fn upper_left(&self) -> Matrix3<P>;
+
+    /// Returns the normal matrix: the inverse-transpose of the upper-left 3x3 matrix, which
+    /// keeps normals perpendicular to surfaces under non-uniform scaling.
+    fn normal_matrix(&self) -> Result<Matrix3<P>, MatrixError>;
+}
+
+// Implementation of the approx traits for Matrix4, comparing every element with the
+// tolerances of the scalar type.
+#[cfg(feature = "approx")]
+impl<P> approx::AbsDiffEq for Matrix4<P>
+where
+    P: Scalar + approx::AbsDiffEq,
+    P::Epsilon: Copy,
+{
+    type Epsilon = P::Epsilon;
+
+    fn default_epsilon() -> P::Epsilon {
+        P::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: P::Epsilon) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<P> approx::RelativeEq for Matrix4<P>
+where
+    P: Scalar + approx::RelativeEq,
+    P::Epsilon: Copy,
+{
+    fn default_max_relative() -> P::Epsilon {
+        P::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: P::Epsilon, max_relative: P::Epsilon) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+// Associated consts of the Matrix4 types with floating point elements.
+macro_rules! impl_float_consts {
+    ($($t:ty),*) => {
+        $(
+            impl Matrix4<$t> {
+                /// Matrix4 with all its elements set to 0.
+                pub const ZERO: Matrix4<$t> = Matrix4 { m: [[0.0; 4]; 4] };
+                /// Identity Matrix4.
+                pub const IDENTITY: Matrix4<$t> = Matrix4 {
+                    m: [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ],
+                };
+            }
+        )*
+    };
+}
+
+impl_float_consts!(f32, f64);
+
+// Identity Matrix4 for any scalar type, used by the constructors of Matrix4Ops.
+pub(crate) fn identity_matrix<P>() -> Matrix4<P>
+where
+    P: Copy + Num,
+{
+    let one: P = num::one();
+    let zero: P = num::zero();
+    Matrix4 {
+        m: [
+            [one, zero, zero, zero],
+            [zero, one, zero, zero],
+            [zero, zero, one, zero],
+            [zero, zero, zero, one],
+        ],
+    }
 }
 
 impl<P> Matrix4Ops<P> for Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     fn equal(&self, other: &Self) -> bool {
         let mut flag = true;
@@ -276,99 +490,104 @@ where
     }
 
     fn get_row(&self, index: Matrix4Index) -> Matrix4Row<P> {
-        let mut row: Matrix4Row<P> = [num::zero(); 4];
-        match index {
-            Matrix4Index::One => {
-                row[0] = self.m[0][0];
-                row[1] = self.m[0][1];
-                row[2] = self.m[0][2];
-                row[3] = self.m[0][3];
-            }
-            Matrix4Index::Two => {
-                row[1] = self.m[1][0];
-                row[1] = self.m[1][1];
-                row[2] = self.m[1][2];
-                row[3] = self.m[1][3];
-            }
-            Matrix4Index::Three => {
-                row[0] = self.m[2][0];
-                row[1] = self.m[2][1];
-                row[2] = self.m[2][2];
-                row[3] = self.m[2][3];
-            }
-            Matrix4Index::Four => {
-                row[0] = self.m[3][0];
-                row[1] = self.m[3][1];
-                row[2] = self.m[3][2];
-                row[3] = self.m[3][3];
-            }
-        }
-        row
+        self.m[index.position()]
     }
 
     fn get_col(&self, index: Matrix4Index) -> Matrix4Col<P> {
-        let mut col: Matrix4Col<P> = [num::zero(); 4];
-        match index {
-            Matrix4Index::One => {
-                col[0] = self.m[0][0];
-                col[1] = self.m[1][0];
-                col[2] = self.m[2][0];
-                col[3] = self.m[3][0];
-            }
-            Matrix4Index::Two => {
-                col[1] = self.m[1][0];
-                col[1] = self.m[1][1];
-                col[2] = self.m[1][2];
-                col[3] = self.m[1][3];
-            }
-            Matrix4Index::Three => {
-                col[0] = self.m[2][0];
-                col[1] = self.m[2][1];
-                col[2] = self.m[2][2];
-                col[3] = self.m[2][3];
-            }
-            Matrix4Index::Four => {
-                col[0] = self.m[3][0];
-                col[1] = self.m[3][1];
-                col[2] = self.m[3][2];
-                col[3] = self.m[3][3];
-            }
+        let col = index.position();
+        [
+            self.m[0][col],
+            self.m[1][col],
+            self.m[2][col],
+            self.m[3][col],
+        ]
+    }
+
+    fn set_row(&mut self, index: Matrix4Index, row: Matrix4Row<P>) {
+        self.m[index.position()] = row;
+    }
+
+    fn set_col(&mut self, index: Matrix4Index, col: Matrix4Col<P>) {
+        let position = index.position();
+        for (row, value) in self.m.iter_mut().zip(col) {
+            row[position] = value;
         }
-        col
     }
 
     fn identity() -> Self {
-        let one: P = num::one();
-        let zero: P = num::zero();
-        Self {
-            m: [
-                [one, zero, zero, zero],
-                [zero, one, zero, zero],
-                [zero, zero, one, zero],
-                [zero, zero, zero, one],
-            ],
-        }
+        identity_matrix()
     }
 
     fn inverse(self) -> Self {
-        if self.determinant() == num::zero() {
-            panic!("Matrix cannot be inversed");
-        } else {
-            let mut res = Matrix4::zero();
-            for row in 0..4 {
-                for col in 0..4 {
-                    let c = self.cofactor(row, col);
-                    // switches col for row to achieve transpose operation
-                    res.m[col][row] = c / self.determinant();
-                }
-            }
-            res
+        match self.try_inverse() {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
         }
     }
 
+    // Analytic adjugate inverse: the 2x2 sub-determinants of the two upper rows ('s') and
+    // of the two lower rows ('c') are computed once, and give both the determinant and
+    // every cofactor.
+    fn try_inverse(self) -> Result<Self, MatrixError> {
+        let a = self.m;
+        let s0 = a[0][0] * a[1][1] - a[1][0] * a[0][1];
+        let s1 = a[0][0] * a[1][2] - a[1][0] * a[0][2];
+        let s2 = a[0][0] * a[1][3] - a[1][0] * a[0][3];
+        let s3 = a[0][1] * a[1][2] - a[1][1] * a[0][2];
+        let s4 = a[0][1] * a[1][3] - a[1][1] * a[0][3];
+        let s5 = a[0][2] * a[1][3] - a[1][2] * a[0][3];
+
+        let c5 = a[2][2] * a[3][3] - a[3][2] * a[2][3];
+        let c4 = a[2][1] * a[3][3] - a[3][1] * a[2][3];
+        let c3 = a[2][1] * a[3][2] - a[3][1] * a[2][2];
+        let c2 = a[2][0] * a[3][3] - a[3][0] * a[2][3];
+        let c1 = a[2][0] * a[3][2] - a[3][0] * a[2][2];
+        let c0 = a[2][0] * a[3][1] - a[3][0] * a[2][1];
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if det == num::zero() {
+            return Err(MatrixError::Singular);
+        }
+
+        let adjugate = [
+            [
+                a[1][1] * c5 - a[1][2] * c4 + a[1][3] * c3,
+                -a[0][1] * c5 + a[0][2] * c4 - a[0][3] * c3,
+                a[3][1] * s5 - a[3][2] * s4 + a[3][3] * s3,
+                -a[2][1] * s5 + a[2][2] * s4 - a[2][3] * s3,
+            ],
+            [
+                -a[1][0] * c5 + a[1][2] * c2 - a[1][3] * c1,
+                a[0][0] * c5 - a[0][2] * c2 + a[0][3] * c1,
+                -a[3][0] * s5 + a[3][2] * s2 - a[3][3] * s1,
+                a[2][0] * s5 - a[2][2] * s2 + a[2][3] * s1,
+            ],
+            [
+                a[1][0] * c4 - a[1][1] * c2 + a[1][3] * c0,
+                -a[0][0] * c4 + a[0][1] * c2 - a[0][3] * c0,
+                a[3][0] * s4 - a[3][1] * s2 + a[3][3] * s0,
+                -a[2][0] * s4 + a[2][1] * s2 - a[2][3] * s0,
+            ],
+            [
+                -a[1][0] * c3 + a[1][1] * c1 - a[1][2] * c0,
+                a[0][0] * c3 - a[0][1] * c1 + a[0][2] * c0,
+                -a[3][0] * s3 + a[3][1] * s1 - a[3][2] * s0,
+                a[2][0] * s3 - a[2][1] * s1 + a[2][2] * s0,
+            ],
+        ];
+
+        let mut res = Matrix4::new(Some(adjugate));
+        for value in res.m.iter_mut().flatten() {
+            *value = *value / det;
+        }
+        Ok(res)
+    }
+
     fn new(data: Option<Matrix4Data<P>>) -> Self {
         match data {
-            None => Matrix4Ops::zero(),
+            None => Self {
+                m: [[num::zero(); 4]; 4],
+            },
             Some(data) => Self { m: data },
         }
     }
@@ -379,10 +598,32 @@ where
         }
     }
 
+    fn orthonormalized(self) -> Self
+    where
+        P: Float,
+    {
+        let col = |c: usize| Vector3::new(self.m[0][c], self.m[1][c], self.m[2][c]);
+
+        // Gram-Schmidt over the basis vectors stored in the columns.
+        let x = col(0).normalized();
+        let mut y = col(1);
+        y = (y - x * Vector3::dot(y, x)).normalized();
+        let mut z = col(2);
+        z = (z - x * Vector3::dot(z, x) - y * Vector3::dot(z, y)).normalized();
+
+        let mut res = self;
+        for (c, axis) in [x, y, z].iter().enumerate() {
+            res.m[0][c] = axis.x;
+            res.m[1][c] = axis.y;
+            res.m[2][c] = axis.z;
+        }
+        res
+    }
+
     fn rotate_x(&mut self, radians: P) -> Self {
-        let mut res = Matrix4::identity();
-        let p_cos = P::from(radians.to_f64().unwrap().cos()).unwrap();
-        let p_sin = P::from(radians.to_f64().unwrap().sin()).unwrap();
+        let mut res = identity_matrix();
+        let p_cos: P = scalar_from_f64(radians.to_f64().unwrap().cos()).unwrap();
+        let p_sin: P = scalar_from_f64(radians.to_f64().unwrap().sin()).unwrap();
         res.m[1][1] = p_cos;
         res.m[1][2] = -p_sin;
         res.m[2][1] = p_sin;
@@ -392,9 +633,9 @@ where
     }
 
     fn rotate_y(&mut self, radians: P) -> Self {
-        let mut res = Matrix4::identity();
-        let p_cos = P::from(radians.to_f64().unwrap().cos()).unwrap();
-        let p_sin = P::from(radians.to_f64().unwrap().sin()).unwrap();
+        let mut res = identity_matrix();
+        let p_cos: P = scalar_from_f64(radians.to_f64().unwrap().cos()).unwrap();
+        let p_sin: P = scalar_from_f64(radians.to_f64().unwrap().sin()).unwrap();
         res.m[0][0] = p_cos;
         res.m[0][2] = p_sin;
         res.m[2][0] = -p_sin;
@@ -404,9 +645,9 @@ where
     }
 
     fn rotate_z(&mut self, radians: P) -> Self {
-        let mut res = Matrix4::identity();
-        let p_cos = P::from(radians.to_f64().unwrap().cos()).unwrap();
-        let p_sin = P::from(radians.to_f64().unwrap().sin()).unwrap();
+        let mut res = identity_matrix();
+        let p_cos: P = scalar_from_f64(radians.to_f64().unwrap().cos()).unwrap();
+        let p_sin: P = scalar_from_f64(radians.to_f64().unwrap().sin()).unwrap();
         res.m[0][0] = p_cos;
         res.m[0][1] = -p_sin;
         res.m[1][0] = p_sin;
@@ -416,7 +657,7 @@ where
     }
 
     fn scale(&mut self, x: P, y: P, z: P) -> Self {
-        let mut res = Matrix4::identity();
+        let mut res = identity_matrix();
         res.m[0][0] = x;
         res.m[1][1] = y;
         res.m[2][2] = z;
@@ -425,7 +666,7 @@ where
     }
 
     fn shear(&mut self, xy: P, xz: P, yx: P, yz: P, zx: P, zy: P) -> Self {
-        let mut res = Matrix4::identity();
+        let mut res = identity_matrix();
         res.m[0][1] = xy;
         res.m[0][2] = xz;
         res.m[1][0] = yx;
@@ -437,19 +678,19 @@ where
     }
 
     fn transpose(&mut self) -> Self {
-        let mut res = Matrix4::zero();
+        let mut res = Matrix4::new(None);
         for row in 0..4 {
             res.m[0][row] = self.m[row][0];
             res.m[1][row] = self.m[row][1];
             res.m[2][row] = self.m[row][2];
             res.m[3][row] = self.m[row][3];
         }
-        *self = res * *self;
+        *self = res;
         *self
     }
 
     fn translate(&mut self, x: P, y: P, z: P) -> Self {
-        let mut res = Matrix4::identity();
+        let mut res = identity_matrix();
         res.m[0][3] = x;
         res.m[1][3] = y;
         res.m[2][3] = z;
@@ -458,18 +699,102 @@ where
     }
 
     fn to_identity(&mut self) -> Self {
-        *self = Matrix4::identity();
+        *self = identity_matrix();
         *self
     }
 
-    fn zero() -> Self {
-        Self {
-            m: [[num::zero(); 4]; 4],
+    fn from_euler(yaw: P, pitch: P, roll: P) -> Self {
+        identity_matrix()
+            .rotate_z(roll)
+            .rotate_x(pitch)
+            .rotate_y(yaw)
+    }
+
+    fn from_translation(x: P, y: P, z: P) -> Self {
+        identity_matrix().translate(x, y, z)
+    }
+
+    fn from_scale(x: P, y: P, z: P) -> Self {
+        identity_matrix().scale(x, y, z)
+    }
+
+    fn from_rotation_x(radians: P) -> Self {
+        identity_matrix().rotate_x(radians)
+    }
+
+    fn from_rotation_y(radians: P) -> Self {
+        identity_matrix().rotate_y(radians)
+    }
+
+    fn from_rotation_z(radians: P) -> Self {
+        identity_matrix().rotate_z(radians)
+    }
+
+    // Rodrigues' rotation formula, computed in f64.
+    fn from_axis_angle(axis: Vector3<P>, radians: P) -> Self {
+        let axis = axis.v_to_f64();
+        let length = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        if length == 0.0 {
+            return identity_matrix();
         }
+        let (x, y, z) = (axis.x / length, axis.y / length, axis.z / length);
+        let radians = radians.to_f64().unwrap();
+        let (sin, cos) = radians.sin_cos();
+        let k = 1.0 - cos;
+        let rotation = [
+            [cos + x * x * k, x * y * k - z * sin, x * z * k + y * sin],
+            [y * x * k + z * sin, cos + y * y * k, y * z * k - x * sin],
+            [z * x * k - y * sin, z * y * k + x * sin, cos + z * z * k],
+        ];
+
+        let mut res = identity_matrix();
+        for (row, values) in rotation.iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                res.m[row][col] = scalar_from_f64(*value).unwrap();
+            }
+        }
+        res
+    }
+
+    fn from_shear(xy: P, xz: P, yx: P, yz: P, zx: P, zy: P) -> Self {
+        identity_matrix().shear(xy, xz, yx, yz, zx, zy)
+    }
+
+    fn to_euler(&self) -> (P, P, P) {
+        let m = self.mat_to_f64().m;
+        let pitch = (-m[1][2]).clamp(-1.0, 1.0).asin();
+        let (yaw, roll) = if pitch.cos().abs() > EPSILON {
+            (m[0][2].atan2(m[2][2]), m[1][0].atan2(m[1][1]))
+        } else {
+            ((-m[2][0]).atan2(m[0][0]), 0.0)
+        };
+        let angle = |a: f64| scalar_from_f64(a).unwrap();
+        (angle(yaw), angle(pitch), angle(roll))
+    }
+
+    fn view_transform(from: Point3<P>, to: Point3<P>, mut up: Vector3<P>) -> Self
+    where
+        P: Float,
+    {
+        let forward = (to - from).normalized();
+        let left = Vector3::cross(forward, up.normalized());
+        let true_up = Vector3::cross(left, forward);
+        let zero: P = num::zero();
+        let orientation = Matrix4::new(Some([
+            [left.x, left.y, left.z, zero],
+            [true_up.x, true_up.y, true_up.z, zero],
+            [-forward.x, -forward.y, -forward.z, zero],
+            [zero, zero, zero, num::one()],
+        ]));
+        orientation * identity_matrix().translate(-from.x, -from.y, -from.z)
+    }
+
+    fn zero() -> Self {
+        Self::new(None)
     }
 
     fn mat_to_f64(self) -> Matrix4<f64> {
-        let mut m_res = Matrix4::zero();
+        let mut m_res = Matrix4::new(None);
         for row in 0..4 {
             for col in 0..4 {
                 m_res.m[row][col] = self.m[row][col].to_f64().unwrap();
@@ -477,18 +802,51 @@ where
         }
         m_res
     }
+
+    fn upper_left(&self) -> Matrix3<P> {
+        let mut res = Matrix3::new();
+        for row in 0..3 {
+            res.m[row].copy_from_slice(&self.m[row][..3]);
+        }
+        res
+    }
+
+    fn normal_matrix(&self) -> Result<Matrix3<P>, MatrixError> {
+        Ok(self.upper_left().try_inverse()?.transpose())
+    }
+}
+
+// Implementation of the Index and IndexMut traits for Matrix4 by (row, col).
+impl<P> Index<(usize, usize)> for Matrix4<P> {
+    type Output = P;
+
+    fn index(&self, (row, col): (usize, usize)) -> &P {
+        match self.m.get(row).and_then(|r| r.get(col)) {
+            Some(value) => value,
+            None => panic!("Matrix4 index out of bounds: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<P> IndexMut<(usize, usize)> for Matrix4<P> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut P {
+        match self.m.get_mut(row).and_then(|r| r.get_mut(col)) {
+            Some(value) => value,
+            None => panic!("Matrix4 index out of bounds: ({}, {})", row, col),
+        }
+    }
 }
 
 // -- Implementation of Opeperator Overloading
 
 impl<P> Mul for Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     type Output = Matrix4<P>;
 
     fn mul(self, rhs: Self) -> Self {
-        let mut m_res = Matrix4::zero();
+        let mut m_res = Matrix4::new(None);
         for row in 0..4 {
             for col in 0..4 {
                 m_res.m[row][col] = self.m[row][0] * rhs.m[0][col]
@@ -503,7 +861,7 @@ where
 
 impl<P> MulAssign for Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     fn mul_assign(&mut self, rhs: Self) {
         for row in 0..4 {
@@ -519,7 +877,7 @@ where
 
 impl<P> Mul<Vector3<P>> for Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     type Output = Vector3<P>;
 
@@ -560,7 +918,7 @@ where
 
 impl<P> Mul<Point3<P>> for Matrix4<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     type Output = Point3<P>;
 
@@ -601,7 +959,7 @@ where
 
 impl<P> Mul<Matrix4<P>> for Vector3<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     type Output = Vector3<P>;
 
@@ -642,7 +1000,7 @@ where
 
 impl<P> Mul<Matrix4<P>> for Point3<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     type Output = Point3<P>;
 
@@ -681,6 +1039,72 @@ where
     }
 }
 
+impl<P> Default for Matrix3<P>
+where
+    P: Scalar,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> Index<(usize, usize)> for Matrix3<P> {
+    type Output = P;
+
+    fn index(&self, (row, col): (usize, usize)) -> &P {
+        match self.m.get(row).and_then(|r| r.get(col)) {
+            Some(value) => value,
+            None => panic!("Matrix3 index out of bounds: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<P> IndexMut<(usize, usize)> for Matrix3<P> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut P {
+        match self.m.get_mut(row).and_then(|r| r.get_mut(col)) {
+            Some(value) => value,
+            None => panic!("Matrix3 index out of bounds: ({}, {})", row, col),
+        }
+    }
+}
+
+impl<P> Mul for Matrix3<P>
+where
+    P: Scalar,
+{
+    type Output = Matrix3<P>;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut m = [[num::zero(); 3]; 3];
+        for (row, values) in m.iter_mut().enumerate() {
+            for (col, value) in values.iter_mut().enumerate() {
+                *value = self.m[row][0] * rhs.m[0][col]
+                    + self.m[row][1] * rhs.m[1][col]
+                    + self.m[row][2] * rhs.m[2][col];
+            }
+        }
+        Matrix3 { m }
+    }
+}
+
+// Only the x, y, z components are transformed; the result is a direction with w = 0.
+impl<P> Mul<Vector3<P>> for Matrix3<P>
+where
+    P: Scalar,
+{
+    type Output = Vector3<P>;
+
+    fn mul(self, rhs: Vector3<P>) -> Vector3<P> {
+        let row = |r: [P; 3]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z;
+        Vector3 {
+            x: row(self.m[0]),
+            y: row(self.m[1]),
+            z: row(self.m[2]),
+            w: num::zero(),
+        }
+    }
+}
+
 // Implementation of Matrix2 operations to calculate a determinant.
 impl<P> Matrix2<P>
 where
@@ -696,16 +1120,73 @@ where
     }
 }
 
-// Implementation of Matrix3 operations to calculate a determinant and submatrix.
+// Implementation of Matrix3 operations: determinant, submatrix, inverse and transpose.
 impl<P> Matrix3<P>
 where
-    P: Copy + Num + NumCast + Neg + Neg<Output = P>,
+    P: Scalar,
 {
-    pub(crate) fn new() -> Self {
+    /// Returns a new matrix filled with '0'.
+    pub fn new() -> Self {
         let zero: P = num::zero();
         Self { m: [[zero; 3]; 3] }
     }
 
+    /// Returns a new matrix with the data provided by the user, by [row][col].
+    pub fn from_data(data: [[P; 3]; 3]) -> Self {
+        Self { m: data }
+    }
+
+    /// Returns a new identity matrix.
+    pub fn identity() -> Self {
+        let mut res = Self::new();
+        for i in 0..3 {
+            res.m[i][i] = num::one();
+        }
+        res
+    }
+
+    /// Returns the data of the matrix, by [row][col].
+    pub fn data(&self) -> [[P; 3]; 3] {
+        self.m
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        let mut res = Self::new();
+        for row in 0..3 {
+            for col in 0..3 {
+                res.m[col][row] = self.m[row][col];
+            }
+        }
+        res
+    }
+
+    /// Returns the inverse of the matrix, or [`MatrixError::Singular`] if its determinant
+    /// is '0'.
+    pub fn try_inverse(&self) -> Result<Self, MatrixError> {
+        let det = self.determinant();
+        if det == num::zero() {
+            return Err(MatrixError::Singular);
+        }
+        let mut res = Self::new();
+        for row in 0..3 {
+            for col in 0..3 {
+                // switches col for row to achieve transpose operation
+                res.m[col][row] = self.cofactor(row, col) / det;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Returns the inverse of the matrix.
+    /// Panics if the matrix is singular; see try_inverse.
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Ok(res) => res,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
     pub(crate) fn submatrix(self, row_del: usize, col_del: usize) -> Matrix2<P> {
         let mut res = Matrix2::new();
         let mut r_count = 0;
@@ -738,7 +1219,8 @@ where
         }
     }
 
-    pub(crate) fn determinant(self) -> P {
+    /// Returns the determinant of the matrix.
+    pub fn determinant(self) -> P {
         let mut det = num::zero();
         for col in 0..3 {
             det = det + self.m[0][col] * self.cofactor(0, col);
@@ -8,7 +8,6 @@
 
 use std::f64::consts::PI;
 use std::path::Path;
-use std::usize;
 
 use crate::picture::canvas::{Canvas, Pixel};
 use crate::picture::colors::{ColorInit, ColorRgb};
@@ -16,7 +15,9 @@ use crate::picture::colors::{ColorInit, ColorRgb};
 use super::*;
 
 /// Unit tests for Matrix4 types.
+#[allow(deprecated)]
 #[test]
+#[allow(clippy::excessive_precision)]
 fn ut_matrix_creation() {
     println!("{}", Matrix4::<f64>::zero());
 
@@ -26,6 +27,9 @@ fn ut_matrix_creation() {
     println!("{}", m_zero);
     let m_iden = Matrix4::<f64>::identity();
     println!("{}", m_iden);
+    assert_eq!(Matrix4::<f64>::ZERO, m_zero);
+    assert_eq!(Matrix4::<f64>::IDENTITY, m_iden);
+    assert_eq!(Matrix4::<f32>::IDENTITY, Matrix4::identity());
 
     let m1 = Matrix4::new(Some([
         [9.193, 8.122, 18.513, 21.351],
@@ -108,9 +112,9 @@ fn ut_matrix_multiplication() {
     assert_eq!(m3 * v3, Vector3::new(14f64, 22f64, 32f64));
 
     // test identity matrix multiplication
-    assert_eq!(m3, m3 * Matrix4::identity());
+    assert_eq!(m3, m3 * Matrix4::<f64>::IDENTITY);
     assert_eq!(
-        Matrix4::identity() * Vector3::new(1.0, 2.0, 3.0,),
+        Matrix4::<f64>::IDENTITY * Vector3::new(1.0, 2.0, 3.0,),
         Vector3::new(1.0, 2.0, 3.0,)
     );
 }
@@ -125,6 +129,17 @@ fn ut_matrix_transpose() {
     ]));
 
     println!("{}", m1.transpose());
+    assert_eq!(
+        m1,
+        Matrix4::new(Some([
+            [0f64, 9f64, 1f64, 0f64],
+            [9f64, 8f64, 8f64, 0f64],
+            [3f64, 0f64, 5f64, 5f64],
+            [0f64, 8f64, 3f64, 8f64],
+        ]))
+    );
+    let mut identity = Matrix4::<f64>::IDENTITY;
+    assert_eq!(identity.transpose(), Matrix4::<f64>::IDENTITY);
 }
 
 #[test]
@@ -279,40 +294,167 @@ fn ut_matrix_inversion() {
     assert_eq!(mc * mb.inverse(), ma);
 }
 
+#[test]
+// The pure constructors build the expected matrices
+fn ut_matrix_from_constructors() {
+    use std::f64::consts::PI;
+
+    assert_eq!(
+        Matrix4::from_translation(5.0, -3.0, 2.0),
+        Matrix4::new(Some([
+            [1.0, 0.0, 0.0, 5.0],
+            [0.0, 1.0, 0.0, -3.0],
+            [0.0, 0.0, 1.0, 2.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    );
+    assert_eq!(
+        Matrix4::from_scale(2, 3, 4),
+        Matrix4::new(Some([
+            [2, 0, 0, 0],
+            [0, 3, 0, 0],
+            [0, 0, 4, 0],
+            [0, 0, 0, 1]
+        ]))
+    );
+    let h = 2f64.sqrt() / 2.0;
+    assert_eq!(
+        Matrix4::from_rotation_y(PI / 4.0),
+        Matrix4::new(Some([
+            [h, 0.0, h, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-h, 0.0, h, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    );
+    assert_eq!(
+        Matrix4::from_shear(1.0, 0.0, 0.0, 0.0, 0.0, 1.0),
+        Matrix4::new(Some([
+            [1.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    );
+
+    // A rotation around a coordinate axis matches the dedicated constructors.
+    let angle = PI / 3.0;
+    assert_eq!(
+        Matrix4::from_axis_angle(Vector3::new(2.0, 0.0, 0.0), angle),
+        Matrix4::from_rotation_x(angle)
+    );
+    assert_eq!(
+        Matrix4::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), angle),
+        Matrix4::from_rotation_z(angle)
+    );
+    // A third of a turn around the diagonal cycles the axes.
+    let diagonal = Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 1.0), 2.0 * PI / 3.0);
+    assert_eq!(diagonal * Vector3::x_coord(1.0), Vector3::y_coord(1.0));
+    assert_eq!(
+        Matrix4::from_axis_angle(Vector3::zero(), angle),
+        Matrix4::<f64>::IDENTITY
+    );
+}
+
+#[test]
+// Matrix elements are read and written by (row, col), and rows and columns are set whole
+fn ut_matrix_index_and_setters() {
+    let mut m = Matrix4::<f64>::ZERO;
+    m[(0, 3)] = 5.0;
+    m[(2, 1)] += 1.5;
+    assert_eq!(m[(0, 3)], 5.0);
+    assert_eq!(m[(2, 1)], 1.5);
+
+    m.set_row(Matrix4Index::Two, [1.0, 2.0, 3.0, 4.0]);
+    m.set_col(Matrix4Index::Four, [9.0, 8.0, 7.0, 6.0]);
+    assert_eq!(m.get_row(Matrix4Index::Two), [1.0, 2.0, 3.0, 8.0]);
+    assert_eq!(m.get_col(Matrix4Index::Four), [9.0, 8.0, 7.0, 6.0]);
+    assert_eq!(m.get_col(Matrix4Index::Two), [0.0, 2.0, 1.5, 0.0]);
+}
+
+#[test]
+#[should_panic(expected = "Matrix4 index out of bounds: (4, 0)")]
+// Indexing outside of the matrix panics with the offending position
+fn ut_matrix_index_out_of_bounds() {
+    let m = Matrix4::<f64>::IDENTITY;
+    let _ = m[(4, 0)];
+}
+
+#[test]
+// Inverting a singular matrix returns an error instead of panicking
+fn ut_matrix_try_inverse() {
+    let m = Matrix4::from_scale(2.0, 2.0, 2.0);
+    assert_eq!(m.try_inverse(), Ok(m.inverse()));
+
+    let singular = Matrix4::from_scale(1.0, 0.0, 1.0);
+    assert_eq!(singular.try_inverse(), Err(MatrixError::Singular));
+    assert_eq!(
+        MatrixError::Singular.to_string(),
+        "Matrix cannot be inversed: it is singular"
+    );
+}
+
+#[test]
+// The analytic inverse matches the cofactor definition and undoes the matrix
+fn ut_matrix_inverse_adjugate() {
+    let m = Matrix4::new(Some([
+        [-5f64, 2f64, 6f64, -8f64],
+        [1f64, -5f64, 1f64, 8f64],
+        [7f64, 7f64, -6f64, -7f64],
+        [1f64, -3f64, 7f64, 4f64],
+    ]));
+    let inv = m.inverse();
+    for row in 0..4 {
+        for col in 0..4 {
+            let expected = m.cofactor(row, col) / m.determinant();
+            assert!((inv.m[col][row] - expected).abs() < EPSILON);
+        }
+    }
+    assert_eq!(m * inv, Matrix4::<f64>::IDENTITY);
+    assert_eq!(inv * m, Matrix4::<f64>::IDENTITY);
+}
+
+#[test]
+#[should_panic(expected = "singular")]
+// The infallible inverse still panics on a singular matrix
+fn ut_matrix_inverse_singular_panics() {
+    Matrix4::<f64>::ZERO.inverse();
+}
+
 #[test]
 // Test the different matrix transformations and chaining of transformations
 fn ut_matrix_transformations() {
     // Translations
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let p = Point3::new(-3.0, 4.0, 5.0);
     let pt = p * m.translate(5.0, -3.0, 2.0);
     assert_eq!(pt, Point3::new(2.0, 1.0, 7.0));
 
     let p = Point3::new(-3.0, 4.0, 5.0);
-    let pt = p * Matrix4::identity().translate(5.0, -3.0, 2.0).inverse();
+    let pt = p * Matrix4::from_translation(5.0, -3.0, 2.0).inverse();
     assert_eq!(pt, Point3::new(-8.0, 7.0, 3.0));
 
     let v = Vector3::new(-3.0, 4.0, 5.0);
-    assert_eq!(v, v * Matrix4::identity().translate(5.0, -3.0, 2.0));
+    assert_eq!(v, v * Matrix4::from_translation(5.0, -3.0, 2.0));
 
     // Scaling
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let p = Point3::new(-4.0, 6.0, 8.0);
     let pt = p * m.scale(2.0, 3.0, 4.0);
     assert_eq!(pt, Point3::new(-8.0, 18.0, 32.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let v = Vector3::new(-4.0, 6.0, 8.0);
     let vt = v * m.scale(2.0, 3.0, 4.0);
     assert_eq!(vt, Vector3::new(-8.0, 18.0, 32.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let v = Vector3::new(-4.0, 6.0, 8.0);
     let vt = v * m.scale(2.0, 3.0, 4.0).inverse();
     assert_eq!(vt, Vector3::new(-2.0, 2.0, 2.0));
 
     // Test the reflection by scaling with a negative axis
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let p = Point3::new(2.0, 3.0, 4.0);
     let pt = p * m.scale(-1.0, 1.0, 1.0);
     assert_eq!(pt, Point3::new(-2.0, 3.0, 4.0));
@@ -321,27 +463,27 @@ fn ut_matrix_transformations() {
 
     // Rotate around x
     let p = Point3::y_coord(1.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let hq = p * m.rotate_x(PI / 4.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let fq = p * m.rotate_x(PI / 2.0);
     assert_eq!(hq, Point3::new(0.0, 2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0));
     assert_eq!(fq, Point3::z_coord(1.0));
 
     // Rotate around y
     let p = Point3::z_coord(1.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let hq = p * m.rotate_y(PI / 4.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let fq = p * m.rotate_y(PI / 2.0);
     assert_eq!(hq, Point3::new(2f64.sqrt() / 2.0, 0.0, 2f64.sqrt() / 2.0));
     assert_eq!(fq, Point3::x_coord(1.0));
 
     // Rotate around z
     let p = Point3::y_coord(1.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let hq = p * m.rotate_z(PI / 4.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let fq = p * m.rotate_z(PI / 2.0);
     assert_eq!(hq, Point3::new(-2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0, 0.0));
     assert_eq!(fq, Point3::x_coord(-1.0));
@@ -349,43 +491,43 @@ fn ut_matrix_transformations() {
     // Shearing
     let p = Point3::new(2.0, 3.0, 4.0);
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let pt = p * m.shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
     assert_eq!(pt, Point3::new(5.0, 3.0, 4.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let pt = p * m.shear(0.0, 0.0, 1.0, 0.0, 0.0, 0.0);
     assert_eq!(pt, Point3::new(2.0, 5.0, 4.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let pt = p * m.shear(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
     assert_eq!(pt, Point3::new(2.0, 7.0, 4.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let pt = p * m.shear(0.0, 0.0, 0.0, 0.0, 1.0, 0.0);
     assert_eq!(pt, Point3::new(2.0, 3.0, 6.0));
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     let pt = p * m.shear(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
     assert_eq!(pt, Point3::new(2.0, 3.0, 7.0));
 
     // Chaining transformations
     let mut p = Point3::new(1.0, 0.0, 1.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
 
     p = p * m.rotate_x(PI / 2.0);
     println!("{}", p);
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     p = p * m.scale(5.0, 5.0, 5.0);
     println!("{}", p);
 
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     p = p * m.translate(10.0, 5.0, 7.0);
     println!("{}", p);
 
     let mut p1 = Point3::new(1.0, 0.0, 1.0);
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
     p1 = p1
         * m.rotate_x(PI / 2.0)
             .scale(5.0, 5.0, 5.0)
@@ -399,7 +541,7 @@ fn ut_matrix_transformations() {
 fn ut_matrix_clock_exercise() {
     let pix = Point3::new(0.0, 0.0, 1.0);
     let hr = PI / 6.0;
-    let mut m = Matrix4::identity();
+    let mut m = Matrix4::<f64>::IDENTITY;
 
     println!("{}", pix * 100.0);
     for _i in 1..12 {
@@ -407,7 +549,7 @@ fn ut_matrix_clock_exercise() {
     }
 
     let mut can = Canvas::new(200, 200);
-    let mut mc = Matrix4::identity();
+    let mut mc = Matrix4::<f64>::IDENTITY;
     let mut pixel = Pixel::new(0, 0, ColorRgb::green());
     let pix = Point3::new(0.0, 0.0, 1.0);
     let image_path = Path::new("images/test_clock.ppm");
@@ -433,5 +575,168 @@ fn ut_matrix_clock_exercise() {
         can.write_pixel(pixel);
         mc.to_identity();
     }
-    can.write_to_ppm(&image_path);
+    can.write_to_ppm(image_path);
+}
+
+#[test]
+// View transformation matrix for the default orientation
+fn ut_matrix_view_transform_default() {
+    let from = Point3::new(0.0, 0.0, 0.0);
+    let to = Point3::new(0.0, 0.0, -1.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(
+        Matrix4::view_transform(from, to, up),
+        Matrix4::<f64>::IDENTITY
+    );
+}
+
+#[test]
+// View transformation looking in the positive Z direction mirrors X and Z
+fn ut_matrix_view_transform_positive_z() {
+    let from = Point3::new(0.0, 0.0, 0.0);
+    let to = Point3::new(0.0, 0.0, 1.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(
+        Matrix4::view_transform(from, to, up),
+        Matrix4::from_scale(-1.0, 1.0, -1.0)
+    );
+}
+
+#[test]
+// View transformation moves the world, not the eye
+fn ut_matrix_view_transform_moves_world() {
+    let from = Point3::new(0.0, 0.0, 8.0);
+    let to = Point3::new(0.0, 0.0, 0.0);
+    let up = Vector3::new(0.0, 1.0, 0.0);
+    let t = Matrix4::view_transform(from, to, up);
+    assert_eq!(t, Matrix4::from_translation(0.0, 0.0, -8.0));
+    assert_eq!(t * from, Point3::zero());
+}
+
+#[test]
+// Arbitrary view transformation
+fn ut_matrix_view_transform_arbitrary() {
+    let from = Point3::new(1.0, 3.0, 2.0);
+    let to = Point3::new(4.0, -2.0, 8.0);
+    let up = Vector3::new(1.0, 1.0, 0.0);
+    let t = Matrix4::view_transform(from, to, up);
+    assert_eq!(
+        t,
+        Matrix4::new(Some([
+            [-0.50709, 0.50709, 0.67612, -2.36643],
+            [0.76772, 0.60609, 0.12122, -2.82843],
+            [-0.35857, 0.59761, -0.71714, 0.00000],
+            [0.00000, 0.00000, 0.00000, 1.00000],
+        ]))
+    );
+}
+
+#[test]
+// Re-orthonormalization of a drifting rotation matrix
+fn ut_matrix_orthonormalized() {
+    let rot = Matrix4::from_rotation_y(PI / 3.0)
+        .rotate_x(PI / 5.0)
+        .translate(1.0, 2.0, 3.0);
+    assert_eq!(rot.orthonormalized(), rot);
+
+    let mut drifted = rot;
+    drifted.m[0][0] *= 1.01;
+    drifted.m[1][0] += 0.02;
+    drifted.m[2][1] -= 0.015;
+    let fixed = drifted.orthonormalized();
+    assert!((fixed.determinant() - 1.0).abs() < EPSILON);
+    let col = |c: usize| Vector3::new(fixed.m[0][c], fixed.m[1][c], fixed.m[2][c]);
+    assert!((col(0).magnitude() - 1.0).abs() < EPSILON);
+    assert!(Vector3::dot(col(0), col(1)).abs() < EPSILON);
+    assert!(Vector3::dot(col(1), col(2)).abs() < EPSILON);
+    assert_eq!(fixed.m[0][3], 1.0);
+    assert_eq!(fixed.m[2][3], 3.0);
+}
+
+#[test]
+// Euler angles build rotation matrices and are extracted back from them
+fn ut_matrix_euler_angles() {
+    let (yaw, pitch, roll): (f64, f64, f64) = (0.3, -0.7, 1.2);
+    let m = Matrix4::from_euler(yaw, pitch, roll);
+    let expected = Matrix4::from_rotation_z(roll).rotate_x(pitch).rotate_y(yaw);
+    assert_eq!(m, expected);
+
+    let (y, p, r) = m.to_euler();
+    assert!((y - yaw).abs() < EPSILON);
+    assert!((p - pitch).abs() < EPSILON);
+    assert!((r - roll).abs() < EPSILON);
+
+    // A yaw of 90 degrees turns the forward axis into the right axis.
+    let turn = Matrix4::from_euler(90f64.to_radians(), 0.0, 0.0);
+    assert_eq!(turn * Vector3::z_coord(1.0), Vector3::x_coord(1.0));
+
+    // At gimbal lock the roll is folded into the yaw.
+    let locked = Matrix4::from_euler(0.2, PI / 2.0, 0.5);
+    let (y, p, r) = locked.to_euler();
+    assert!((p - PI / 2.0).abs() < EPSILON);
+    assert_eq!(r, 0.0);
+    assert_eq!(Matrix4::from_euler(y, p, r), locked);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+// Tolerance-based comparisons with the approx crate
+fn ut_matrix_approx() {
+    use approx::{assert_abs_diff_eq, assert_relative_ne};
+
+    let m = Matrix4::from_rotation_y(PI / 2.0);
+    let expected = Matrix4::new(Some([
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [-1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]));
+    assert_abs_diff_eq!(m, expected, epsilon = 1e-12);
+    assert_relative_ne!(m, Matrix4::<f64>::IDENTITY);
+}
+
+#[test]
+// Matrix3 multiplication, transpose and inverse
+fn ut_matrix3_operations() {
+    let a = Matrix3::from_data([[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]]);
+    assert_eq!(a * Matrix3::identity(), a);
+    assert_eq!(a.transpose()[(2, 0)], 3.0);
+    assert_eq!(a.determinant(), 1.0);
+    assert_eq!(
+        a.inverse(),
+        Matrix3::from_data([[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]])
+    );
+    assert_eq!(a * a.inverse(), Matrix3::identity());
+    assert_eq!(
+        Matrix3::<f64>::new().try_inverse(),
+        Err(MatrixError::Singular)
+    );
+    assert_eq!(
+        a * Vector3::new(1.0, 1.0, 1.0),
+        Vector3::new(6.0, 5.0, 11.0)
+    );
+}
+
+#[test]
+// The normal matrix keeps normals perpendicular under non-uniform scaling
+fn ut_matrix_normal_matrix() {
+    let m = Matrix4::from_scale(1.0, 0.5, 1.0)
+        .rotate_z(PI / 5.0)
+        .translate(3.0, -2.0, 1.0);
+    assert_eq!(
+        m.upper_left(),
+        Matrix3::from_data([
+            [m.m[0][0], m.m[0][1], m.m[0][2]],
+            [m.m[1][0], m.m[1][1], m.m[1][2]],
+            [m.m[2][0], m.m[2][1], m.m[2][2]],
+        ])
+    );
+
+    let normal = m.normal_matrix().unwrap() * Vector3::new(0.0, 1.0, 0.0);
+    let tangent = m * Vector3::new(1.0, 0.0, 0.0);
+    assert!(Vector3::dot(normal, tangent).abs() < EPSILON);
+    assert_eq!(
+        Matrix4::<f64>::from_scale(0.0, 1.0, 1.0).normal_matrix(),
+        Err(MatrixError::Singular)
+    );
 }
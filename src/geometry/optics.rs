@@ -0,0 +1,70 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Reflection and refraction of Vectors at a surface, shared by the integrators.
+
+The incident Vector points towards the surface and the normal is expected to be
+normalized and to face against it. 'eta' is the ratio n1 / n2 of the refractive index
+of the medium being left over the one being entered.
+*/
+use num::Float;
+
+use super::vector::*;
+
+// Unit tests for the optics functions
+#[cfg(test)]
+mod tests;
+
+/// Returns the Vector 'v' reflected around the normal 'n'.
+pub fn reflect<P>(v: Vector3<P>, n: Vector3<P>) -> Vector3<P>
+where
+    P: Float,
+{
+    let two = P::one() + P::one();
+    v - n * (two * Vector3::dot(v, n))
+}
+
+/// Returns the Vector 'v' refracted through the normal 'n' following Snell's law,
+/// or None on total internal reflection.
+pub fn refract<P>(v: Vector3<P>, n: Vector3<P>, eta: P) -> Option<Vector3<P>>
+where
+    P: Float,
+{
+    let cos_i = -Vector3::dot(v, n);
+    let sin2_t = eta * eta * (P::one() - cos_i * cos_i);
+    if sin2_t > P::one() {
+        return None;
+    }
+    let cos_t = (P::one() - sin2_t).sqrt();
+    let mut t = v * eta + n * (eta * cos_i - cos_t);
+    t.w = P::zero();
+    Some(t)
+}
+
+/// Returns the fraction of light reflected at a surface for the cosine of the angle
+/// between the incident Vector and the normal 'cos_i', using Schlick's approximation.
+/// Returns 1 on total internal reflection.
+pub fn fresnel<P>(cos_i: P, eta: P) -> P
+where
+    P: Float,
+{
+    let one = P::one();
+    let mut cos = cos_i;
+
+    // When leaving a denser medium the angle of the transmitted ray is the one that matters.
+    if eta > one {
+        let sin2_t = eta * eta * (one - cos * cos);
+        if sin2_t > one {
+            return one;
+        }
+        cos = (one - sin2_t).sqrt();
+    }
+    let r0 = ((eta - one) / (eta + one)).powi(2);
+    r0 + (one - r0) * (one - cos).powi(5)
+}
@@ -0,0 +1,55 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the optics functions.
+
+use super::*;
+use crate::geometry::EPSILON;
+
+#[test]
+// Reflecting a Vector approaching at 45 degrees and off a slanted surface
+fn ut_optics_reflect() {
+    let v = Vector3::new(1.0, -1.0, 0.0);
+    assert_eq!(
+        reflect(v, Vector3::y_coord(1.0)),
+        Vector3::new(1.0, 1.0, 0.0)
+    );
+
+    let h = 2f64.sqrt() / 2.0;
+    let v = Vector3::new(0.0, -1.0, 0.0);
+    assert_eq!(reflect(v, Vector3::new(h, h, 0.0)), Vector3::x_coord(1.0));
+}
+
+#[test]
+// Refraction bends towards the normal entering glass and can totally reflect leaving it
+fn ut_optics_refract() {
+    let n = Vector3::y_coord(1.0);
+    assert_eq!(
+        refract(Vector3::y_coord(-1.0), n, 1.0 / 1.5),
+        Some(Vector3::y_coord(-1.0))
+    );
+
+    let h = 2f64.sqrt() / 2.0;
+    let v = Vector3::new(h, -h, 0.0);
+    let t = refract(v, n, 1.0 / 1.5).unwrap();
+    // Snell's law: n1 * sin(i) = n2 * sin(t).
+    assert!((t.x - h / 1.5).abs() < EPSILON);
+    assert!((t.magnitude() - 1.0).abs() < EPSILON);
+
+    assert_eq!(refract(v, n, 1.5), None);
+}
+
+#[test]
+// Reflectance grows at grazing angles and is total beyond the critical angle
+fn ut_optics_fresnel() {
+    let normal: f64 = fresnel(1.0, 1.0 / 1.5);
+    assert!((normal - 0.04).abs() < EPSILON);
+    assert!(fresnel(0.1, 1.0 / 1.5) > normal);
+    assert_eq!(fresnel(0.5, 1.5), 1.0);
+    assert!(fresnel(1.0f64, 1.0).abs() < EPSILON);
+}
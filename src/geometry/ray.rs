@@ -7,10 +7,12 @@
 // except according to those terms.
 
 use crate::geometry::matrix::*;
+use crate::geometry::scalar::Scalar;
+use crate::geometry::scalar_from_f64;
 use crate::shapes::*;
-use num::{Num, NumCast};
+use num::{Float, Num};
 use std::fmt::Display;
-use std::ops::{Mul, Neg};
+use std::ops::Mul;
 
 /**
  Data structures and methods for Ray computations.
@@ -25,6 +27,7 @@ use super::{vector::*, EPSILON};
 /// Type representing a Ray with an Origin (Point3) and
 /// a Direction (Vector3).
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ray<P> {
     /// Origin of a Ray represented by a Point3 type.
     pub origin: Point3<P>,
@@ -43,6 +46,72 @@ where
     }
 }
 
+/**
+Type representing a Ray together with the offset Rays of its neighbouring pixels
+in X and Y ('ray differentials'), which describe the footprint of the Ray and drive
+texture filtering.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct RayDifferential<P> {
+    /// Main Ray.
+    pub ray: Ray<P>,
+    /// Ray offset by one pixel in the X direction.
+    pub rx: Ray<P>,
+    /// Ray offset by one pixel in the Y direction.
+    pub ry: Ray<P>,
+}
+
+impl<P> RayDifferential<P>
+where
+    P: Scalar,
+{
+    /// Creates a new RayDifferential from a Ray and its X and Y offset Rays.
+    pub fn new(ray: Ray<P>, rx: Ray<P>, ry: Ray<P>) -> Self {
+        Self { ray, rx, ry }
+    }
+
+    /// Returns the differential of the Origin in X and Y (dP/dx, dP/dy).
+    pub fn d_origin(&self) -> (Vector3<P>, Vector3<P>) {
+        (
+            self.rx.origin - self.ray.origin,
+            self.ry.origin - self.ray.origin,
+        )
+    }
+
+    /// Returns the differential of the Direction in X and Y (dD/dx, dD/dy).
+    pub fn d_direction(&self) -> (Vector3<P>, Vector3<P>) {
+        (
+            self.rx.direction - self.ray.direction,
+            self.ry.direction - self.ray.direction,
+        )
+    }
+
+    /// Transforms the Ray and its differentials given a Transformation Matrix.
+    pub fn transform(self, mat: Matrix4<P>) -> Self {
+        Self {
+            ray: Ray::transform(self.ray, mat),
+            rx: Ray::transform(self.rx, mat),
+            ry: Ray::transform(self.ry, mat),
+        }
+    }
+
+    /// Returns the approximate width of the footprint of the Ray at a distance 't',
+    /// as the largest distance between its Position and the offset Rays positions.
+    pub fn footprint(&self, t: P) -> P
+    where
+        P: Float,
+    {
+        let p = Ray::position(self.ray, t);
+        let dx = (Ray::position(self.rx, t) - p).magnitude();
+        let dy = (Ray::position(self.ry, t) - p).magnitude();
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
 /// A trait that provides common operations for Rays
 pub trait Rays<P> {
     /// Creates and returns a new Ray with Origin (Point3)
@@ -62,7 +131,7 @@ pub trait Rays<P> {
 
 impl<P> Rays<P> for Ray<P>
 where
-    P: Num + NumCast + Copy + Display + Neg + Neg<Output = P>,
+    P: Scalar,
 {
     fn new(origin: Point3<P>, direction: Vector3<P>) -> Self {
         Self { origin, direction }
@@ -86,3 +155,76 @@ where
         }
     }
 }
+
+/// Returns the 't' values where a Ray enters and exits a sphere of a given center and radius,
+/// or None if the Ray misses it.
+pub fn intersect_sphere<P>(ray: Ray<P>, center: Point3<P>, radius: P) -> Option<(P, P)>
+where
+    P: Scalar,
+{
+    let ray = ray.ray_to_f64();
+    let radius = radius.to_f64().unwrap();
+
+    let sphere_to_ray = ray.origin - center.p_to_f64();
+    let a = Vector3::dot(ray.direction, ray.direction);
+    let b = 2.0 * Vector3::dot(ray.direction, sphere_to_ray);
+    let c = Vector3::dot(sphere_to_ray, sphere_to_ray) - radius * radius;
+
+    let discriminant = b * b - (4.0 * a * c);
+    if discriminant < 0.0 || a == 0.0 {
+        return None;
+    }
+    let t1 = (-b - discriminant.sqrt()) / (2.0 * a);
+    let t2 = (-b + discriminant.sqrt()) / (2.0 * a);
+    Some((scalar_from_f64(t1)?, scalar_from_f64(t2)?))
+}
+
+/// Returns the 't' value where a Ray crosses the plane passing through 'point' with a given
+/// 'normal', or None if the Ray is parallel to the plane.
+pub fn intersect_plane<P>(ray: Ray<P>, point: Point3<P>, normal: Vector3<P>) -> Option<P>
+where
+    P: Scalar,
+{
+    let ray = ray.ray_to_f64();
+    let normal = normal.v_to_f64();
+
+    let denom = Vector3::dot(normal, ray.direction);
+    if denom.abs() < EPSILON {
+        return None;
+    }
+    scalar_from_f64(Vector3::dot(point.p_to_f64() - ray.origin, normal) / denom)
+}
+
+/// Returns the 't' values where a Ray enters and exits the axis-aligned bounding box
+/// defined by its 'min' and 'max' corners, or None if the Ray misses it or the box lies
+/// entirely behind the Ray origin. The entry 't' is negative when the origin is inside.
+pub fn intersect_aabb<P>(ray: Ray<P>, min: Point3<P>, max: Point3<P>) -> Option<(P, P)>
+where
+    P: Scalar,
+{
+    let ray = ray.ray_to_f64();
+    let (min, max) = (min.p_to_f64(), max.p_to_f64());
+
+    let mut t_min = f64::NEG_INFINITY;
+    let mut t_max = f64::INFINITY;
+    for axis in 0..3 {
+        let (origin, direction) = (ray.origin[axis], ray.direction[axis]);
+        let (lo, hi) = (min[axis], max[axis]);
+        if direction.abs() < EPSILON {
+            // Parallel to the slab: misses unless the origin lies between its planes.
+            if origin < lo || origin > hi {
+                return None;
+            }
+            continue;
+        }
+        let t1 = (lo - origin) / direction;
+        let t2 = (hi - origin) / direction;
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+    }
+
+    if t_min > t_max || t_max < 0.0 {
+        return None;
+    }
+    Some((scalar_from_f64(t_min)?, scalar_from_f64(t_max)?))
+}
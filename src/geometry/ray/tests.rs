@@ -25,7 +25,7 @@ fn ut_ray_initialization() {
 #[test]
 // validate proper ray position calculation
 fn ut_ray_position() {
-    let ray = Ray::new(Point3::new(2.0, 3.0, 4.0), Vector3::right());
+    let ray = Ray::new(Point3::new(2.0, 3.0, 4.0), Vector3::x_coord(1.0));
     assert_eq!(Ray::position(ray, 0.0), Point3::new(2.0, 3.0, 4.0));
     assert_eq!(Ray::position(ray, 1.0), Point3::new(3.0, 3.0, 4.0));
     assert_eq!(Ray::position(ray, -1.0), Point3::new(1.0, 3.0, 4.0));
@@ -35,19 +35,116 @@ fn ut_ray_position() {
 #[test]
 // translating ray
 fn ut_ray_translation() {
-    let ray = Ray::new(Point3::new(1, 2, 3), Vector3::up());
-    let mat = Matrix4::identity().translate(3, 4, 5);
+    let ray = Ray::new(Point3::new(1, 2, 3), Vector3::y_coord(1));
+    let mat = Matrix4::from_translation(3, 4, 5);
     let ray2 = Ray::transform(ray, mat);
     assert_eq!(ray2.origin, Point3::new(4, 6, 8));
-    assert_eq!(ray2.direction, Vector3::up());
+    assert_eq!(ray2.direction, Vector3::y_coord(1));
 }
 
 #[test]
 // scaling ray
 fn ut_ray_scaling() {
-    let ray = Ray::new(Point3::new(1, 2, 3), Vector3::up());
-    let mat = Matrix4::identity().scale(2, 3, 4);
+    let ray = Ray::new(Point3::new(1, 2, 3), Vector3::y_coord(1));
+    let mat = Matrix4::from_scale(2, 3, 4);
     let ray2 = Ray::transform(ray, mat);
     assert_eq!(ray2.origin, Point3::new(2, 6, 12));
     assert_eq!(ray2.direction, Vector3::y_coord(3));
 }
+
+#[test]
+// ray-sphere free function intersection
+fn ut_ray_intersect_sphere() {
+    let ray = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::z_coord(1.0));
+    assert_eq!(intersect_sphere(ray, Point3::zero(), 1.0), Some((4.0, 6.0)));
+    assert_eq!(
+        intersect_sphere(ray, Point3::new(0.0, 0.0, 2.0), 2.0),
+        Some((5.0, 9.0))
+    );
+    assert_eq!(intersect_sphere(ray, Point3::new(0.0, 3.0, 0.0), 1.0), None);
+}
+
+#[test]
+// ray-plane free function intersection
+fn ut_ray_intersect_plane() {
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::y_coord(-1.0));
+    assert_eq!(
+        intersect_plane(ray, Point3::zero(), Vector3::y_coord(1.0)),
+        Some(1.0)
+    );
+    let ray = Ray::new(Point3::new(0.0, 1.0, 0.0), Vector3::x_coord(1.0));
+    assert_eq!(
+        intersect_plane(ray, Point3::zero(), Vector3::y_coord(1.0)),
+        None
+    );
+}
+
+#[test]
+// ray-aabb free function intersection
+fn ut_ray_intersect_aabb() {
+    let (min, max) = (Point3::all(-1.0), Point3::all(1.0));
+    let ray = Ray::new(Point3::new(5.0, 0.5, 0.0), Vector3::x_coord(-1.0));
+    assert_eq!(intersect_aabb(ray, min, max), Some((4.0, 6.0)));
+    let ray = Ray::new(Point3::new(0.5, 0.0, 0.0), Vector3::z_coord(1.0));
+    assert_eq!(intersect_aabb(ray, min, max), Some((-1.0, 1.0)));
+    let ray = Ray::new(
+        Point3::new(-2.0, 0.0, 0.0),
+        Vector3::new(0.2673, 0.5345, 0.8018),
+    );
+    assert_eq!(intersect_aabb(ray, min, max), None);
+    let ray = Ray::new(Point3::new(0.0, 2.0, 0.0), Vector3::z_coord(1.0));
+    assert_eq!(intersect_aabb(ray, min, max), None);
+    // The box is entirely behind the Ray origin.
+    let ray = Ray::new(Point3::new(5.0, 0.5, 0.0), Vector3::x_coord(1.0));
+    assert_eq!(intersect_aabb(ray, min, max), None);
+}
+
+#[test]
+// ray differentials are propagated through transformations
+fn ut_ray_differential_transform() {
+    let ray: Ray<f64> = Ray::new(Point3::zero(), Vector3::z_coord(1.0));
+    let rx = Ray::new(Point3::zero(), Vector3::new(0.01, 0.0, 1.0));
+    let ry = Ray::new(Point3::zero(), Vector3::new(0.0, 0.02, 1.0));
+    let rd = RayDifferential::new(ray, rx, ry);
+    assert_eq!(rd.d_origin(), (Vector3::zero(), Vector3::zero()));
+    assert_eq!(rd.d_direction().1, Vector3::new(0.0, 0.02, 0.0));
+    assert!((rd.footprint(10.0) - 0.2).abs() < 1e-9);
+
+    let moved = rd.transform(Matrix4::from_scale(2.0, 2.0, 2.0).translate(0.0, 1.0, 0.0));
+    assert_eq!(moved.ray.origin, Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(moved.d_direction().0, Vector3::new(0.02, 0.0, 0.0));
+    assert!((moved.footprint(10.0) - 0.4).abs() < 1e-9);
+}
+
+#[test]
+// Integer rays compute in f64 and round the intersections to the nearest integer
+fn ut_ray_intersect_sphere_integer() {
+    let r: Ray<i32> = Ray::new(Point3::new(0, 0, -5), Vector3::new(0, 0, 2));
+    // The exact values are 2.0 and 3.0.
+    assert_eq!(intersect_sphere(r, Point3::zero(), 1), Some((2, 3)));
+    // The exact values are 1.0 and 4.0.
+    assert_eq!(intersect_sphere(r, Point3::zero(), 3), Some((1, 4)));
+
+    let r: Ray<i32> = Ray::new(Point3::new(0, 0, -5), Vector3::new(0, 0, 4));
+    // The exact values are 1.0 and 1.5, which rounds away from zero.
+    assert_eq!(intersect_sphere(r, Point3::zero(), 1), Some((1, 2)));
+
+    // Values that do not fit in the scalar type return None instead of panicking.
+    let r: Ray<i8> = Ray::new(Point3::new(0, 0, -127), Vector3::new(0, 0, 1));
+    assert_eq!(intersect_sphere(r, Point3::zero(), 1), None);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+// A transformed Ray round-trips through JSON
+fn ut_ray_serde_round_trip() {
+    let r = Ray::new(Point3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 1.0, 0.0));
+    let json = serde_json::to_string(&r).unwrap();
+    let back: Ray<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.origin, r.origin);
+    assert_eq!(back.direction, r.direction);
+
+    let m = Matrix4::from_translation(3.0, 4.0, 5.0).scale(2.0, 2.0, 2.0);
+    let back: Matrix4<f64> = serde_json::from_str(&serde_json::to_string(&m).unwrap()).unwrap();
+    assert_eq!(back, m);
+}
@@ -0,0 +1,75 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+The Scalar trait gathers the numeric bounds required by the geometry types, so new code
+can write 'P: Scalar' instead of repeating 'P: Copy + Num + NumCast + Neg<Output = P> + ...'.
+*/
+use std::fmt::{Debug, Display};
+use std::ops::Neg;
+
+use num::{Num, NumCast};
+
+#[cfg(feature = "wide")]
+use super::{matrix::Matrix4, ray::Ray, simd::SimdMatrix4};
+
+// Scalar Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Numeric type usable as the 'P' component of Vectors, Points, Matrices and Shapes.
+/// Implemented for the signed integer and floating point primitives.
+pub trait Scalar:
+    Copy + Num + NumCast + Neg<Output = Self> + PartialOrd + Display + Debug + Default
+{
+    /// Tolerance used to compare two values of the type: [`EPSILON`](super::EPSILON)
+    /// for floating point types, and '0' for integer types.
+    const EPSILON: Self;
+
+    /// Converts a matrix to a [`SimdMatrix4`], or returns None if the type has no SIMD
+    /// path. Overridden for f64, whose [`Transform`](super::transform::Transform) caches
+    /// its inverse this way.
+    #[cfg(feature = "wide")]
+    fn simd_matrix(_matrix: Matrix4<Self>) -> Option<SimdMatrix4> {
+        None
+    }
+
+    /// Transforms a Ray with a [`SimdMatrix4`], or returns None if the type has no SIMD
+    /// path. Overridden for f64.
+    #[cfg(feature = "wide")]
+    fn simd_transform_ray(_ray: Ray<Self>, _matrix: &SimdMatrix4) -> Option<Ray<Self>> {
+        None
+    }
+}
+
+macro_rules! impl_scalar {
+    ($epsilon:expr => $($t:ty),*) => {
+        $(
+            impl Scalar for $t {
+                const EPSILON: Self = $epsilon;
+            }
+        )*
+    };
+}
+
+impl_scalar!(0 => i8, i16, i32, i64, i128, isize);
+impl_scalar!(super::EPSILON as f32 => f32);
+
+impl Scalar for f64 {
+    const EPSILON: Self = super::EPSILON;
+
+    #[cfg(feature = "wide")]
+    fn simd_matrix(matrix: Matrix4<Self>) -> Option<SimdMatrix4> {
+        Some(SimdMatrix4::from(matrix))
+    }
+
+    #[cfg(feature = "wide")]
+    fn simd_transform_ray(ray: Ray<Self>, matrix: &SimdMatrix4) -> Option<Ray<Self>> {
+        Some(matrix.transform_ray(ray))
+    }
+}
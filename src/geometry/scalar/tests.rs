@@ -0,0 +1,37 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the Scalar trait.
+
+use super::*;
+use crate::geometry::matrix::{Matrix4, Matrix4Ops};
+use crate::geometry::vector::{Point3, Tuple};
+
+// Generic code only needs the Scalar bound to use the geometry types.
+fn translate_and_compare<P: Scalar>(p: Point3<P>, by: P, expected: Point3<P>) -> bool {
+    let moved = Matrix4::from_translation(by, by, by) * p;
+    (moved.x - expected.x) <= P::EPSILON && (expected.x - moved.x) <= P::EPSILON
+}
+
+#[test]
+// Integer and floating point types satisfy the Scalar bound
+fn ut_scalar_bounds() {
+    assert!(translate_and_compare(
+        Point3::new(1, 2, 3),
+        2,
+        Point3::new(3, 4, 5)
+    ));
+    assert!(translate_and_compare(
+        Point3::new(1.0, 2.0, 3.0),
+        0.5,
+        Point3::new(1.50001, 2.5, 3.5)
+    ));
+    assert_eq!(<i32 as Scalar>::EPSILON, 0);
+    assert_eq!(<f32 as Scalar>::EPSILON, crate::geometry::EPSILON as f32);
+    assert_eq!(<f64 as Scalar>::EPSILON, crate::geometry::EPSILON);
+}
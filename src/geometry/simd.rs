@@ -0,0 +1,136 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+SIMD-backed storage for f64 Vectors, Points and Matrices, enabled with the 'wide' feature.
+The x, y, z, w components are stored in the four lanes of an f64x4, and a Matrix4 in four
+column lanes, so a matrix product is four broadcast multiply-adds per column.
+A [`Transform`](super::transform::Transform) of f64 caches its inverse as a SimdMatrix4,
+which transforms every Ray to object space with [`SimdMatrix4::transform_ray`].
+*/
+use std::ops::Mul;
+
+use wide::f64x4;
+
+use crate::geometry::{
+    matrix::{Matrix4, Matrix4Ops},
+    ray::Ray,
+    vector::{Point3, Vector3},
+};
+
+// SIMD Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Vector3 or Point3 of f64 stored in SIMD lanes as (x, y, z, w).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SimdTuple4(pub f64x4);
+
+/// Matrix4 of f64 stored as four SIMD columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SimdMatrix4 {
+    cols: [f64x4; 4],
+}
+
+impl SimdTuple4 {
+    /// Returns the dot product of the x, y, z and w components.
+    pub fn dot(self, other: SimdTuple4) -> f64 {
+        (self.0 * other.0).reduce_add()
+    }
+
+    /// Returns the cross product of the x, y, z components; w is zero, like a Vector3.
+    pub fn cross(self, other: SimdTuple4) -> SimdTuple4 {
+        // a x b = (a * b.yzx - a.yzx * b).yzx, which needs three lane rotations instead of four.
+        SimdTuple4(yzx(self.0 * yzx(other.0) - yzx(self.0) * other.0))
+    }
+}
+
+// Rotates the x, y, z lanes to y, z, x and clears w.
+// wide has no lane permutation for f64x4, so the lanes are moved through the array view.
+fn yzx(lanes: f64x4) -> f64x4 {
+    let [x, y, z, _] = *lanes.as_array_ref();
+    f64x4::new([y, z, x, 0.0])
+}
+
+impl From<Vector3<f64>> for SimdTuple4 {
+    fn from(v: Vector3<f64>) -> Self {
+        SimdTuple4(f64x4::new([v.x, v.y, v.z, v.w]))
+    }
+}
+
+impl From<Point3<f64>> for SimdTuple4 {
+    fn from(p: Point3<f64>) -> Self {
+        SimdTuple4(f64x4::new([p.x, p.y, p.z, p.w]))
+    }
+}
+
+impl From<SimdTuple4> for Vector3<f64> {
+    fn from(t: SimdTuple4) -> Self {
+        let [x, y, z, w] = t.0.to_array();
+        Vector3 { x, y, z, w }
+    }
+}
+
+impl From<SimdTuple4> for Point3<f64> {
+    fn from(t: SimdTuple4) -> Self {
+        let [x, y, z, w] = t.0.to_array();
+        Point3 { x, y, z, w }
+    }
+}
+
+impl SimdMatrix4 {
+    /// Transforms the origin and direction of a Ray, like [`Rays::transform`](super::ray::Rays::transform).
+    pub fn transform_ray(&self, ray: Ray<f64>) -> Ray<f64> {
+        Ray {
+            origin: (*self * SimdTuple4::from(ray.origin)).into(),
+            direction: (*self * SimdTuple4::from(ray.direction)).into(),
+        }
+    }
+}
+
+impl From<Matrix4<f64>> for SimdMatrix4 {
+    fn from(m: Matrix4<f64>) -> Self {
+        let data = m.data();
+        let col = |c: usize| f64x4::new([data[0][c], data[1][c], data[2][c], data[3][c]]);
+        SimdMatrix4 {
+            cols: [col(0), col(1), col(2), col(3)],
+        }
+    }
+}
+
+impl From<SimdMatrix4> for Matrix4<f64> {
+    fn from(m: SimdMatrix4) -> Self {
+        let cols = m.cols.map(|c| c.to_array());
+        let row = |r: usize| [cols[0][r], cols[1][r], cols[2][r], cols[3][r]];
+        Matrix4::new(Some([row(0), row(1), row(2), row(3)]))
+    }
+}
+
+impl Mul for SimdMatrix4 {
+    type Output = SimdMatrix4;
+
+    // Every result column is the lhs matrix applied to the rhs column.
+    fn mul(self, rhs: SimdMatrix4) -> SimdMatrix4 {
+        SimdMatrix4 {
+            cols: rhs.cols.map(|col| (self * SimdTuple4(col)).0),
+        }
+    }
+}
+
+impl Mul<SimdTuple4> for SimdMatrix4 {
+    type Output = SimdTuple4;
+
+    // The columns weighted by the broadcast x, y, z, w components, with fused multiply-adds.
+    fn mul(self, rhs: SimdTuple4) -> SimdTuple4 {
+        let [x, y, z, w] = *rhs.0.as_array_ref();
+        let res = self.cols[0] * f64x4::splat(x);
+        let res = self.cols[1].mul_add(f64x4::splat(y), res);
+        let res = self.cols[2].mul_add(f64x4::splat(z), res);
+        SimdTuple4(self.cols[3].mul_add(f64x4::splat(w), res))
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the SIMD-backed types.
+
+use super::*;
+use crate::geometry::vector::{Tuple, Vector};
+
+#[test]
+// Vectorized dot and cross products match the scalar ones
+fn ut_simd_dot_cross() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(2.0, 3.0, 4.0);
+    let (sa, sb) = (SimdTuple4::from(a), SimdTuple4::from(b));
+    assert_eq!(sa.dot(sb), Vector3::dot(a, b));
+    assert_eq!(Vector3::from(sa.cross(sb)), Vector3::cross(a, b));
+    assert_eq!(Vector3::from(sb.cross(sa)), Vector3::new(1.0, -2.0, 1.0));
+}
+
+#[test]
+// Vectorized Matrix4 products match the scalar ones
+fn ut_simd_matrix_mul() {
+    let a = Matrix4::from_rotation_x(0.5)
+        .scale(2.0, 3.0, 4.0)
+        .translate(1.0, -2.0, 3.0);
+    let b = Matrix4::from_shear(1.0, 0.0, 0.5, 0.0, 0.0, 2.0);
+    let product = SimdMatrix4::from(a) * SimdMatrix4::from(b);
+    assert_eq!(Matrix4::from(product), a * b);
+
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let v = Vector3::new(-1.0, 0.5, 2.0);
+    let sa = SimdMatrix4::from(a);
+    assert_eq!(Point3::from(sa * SimdTuple4::from(p)), a * p);
+    assert_eq!(Vector3::from(sa * SimdTuple4::from(v)), a * v);
+}
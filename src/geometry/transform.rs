@@ -0,0 +1,200 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ Data structures and methods to compose nested transformations, and to cache the inverse
+ of a transformation.
+*/
+use num::Float;
+use std::fmt::Display;
+
+// Bring Matrix4 and Vector types into scope
+use super::matrix::*;
+use super::ray::{Ray, Rays};
+use super::scalar::Scalar;
+#[cfg(feature = "wide")]
+use super::simd::SimdMatrix4;
+use super::vector::*;
+use crate::validate::{validate_vector, Origin};
+
+// Unit tests for TransformStack
+#[cfg(test)]
+mod tests;
+
+/**
+Stack of transformation matrices, in the spirit of the OpenGL matrix stack.
+The bottom of the stack is always the identity matrix, and every pushed
+matrix is composed with the current one, so procedural code can build
+nested hierarchies imperatively.
+*/
+#[derive(Clone, Debug)]
+pub struct TransformStack<P> {
+    stack: Vec<Matrix4<P>>,
+}
+
+impl<P> TransformStack<P>
+where
+    P: Scalar,
+{
+    /// Creates a new [`TransformStack`] whose current transformation is the identity.
+    pub fn new() -> Self {
+        TransformStack {
+            stack: vec![identity_matrix()],
+        }
+    }
+
+    /// Composes 'mat' with the current transformation and pushes the result.
+    pub fn push(&mut self, mat: Matrix4<P>) -> Matrix4<P> {
+        let top = self.current() * mat;
+        self.stack.push(top);
+        top
+    }
+
+    /// Pops the current transformation, returning it.
+    /// The identity at the bottom of the stack is never popped.
+    pub fn pop(&mut self) -> Option<Matrix4<P>> {
+        if self.stack.len() > 1 {
+            self.stack.pop()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current (composed) transformation.
+    pub fn current(&self) -> Matrix4<P> {
+        self.stack[self.stack.len() - 1]
+    }
+
+    /// Returns the number of matrices pushed on top of the identity.
+    pub fn depth(&self) -> usize {
+        self.stack.len() - 1
+    }
+}
+
+impl<P> Default for TransformStack<P>
+where
+    P: Scalar,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> Display for TransformStack<P>
+where
+    P: Copy + Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "TransformStack - depth: {}\n{}",
+            self.stack.len() - 1,
+            self.stack[self.stack.len() - 1]
+        );
+        f.write_str(&s)
+    }
+}
+
+/**
+Transformation matrix together with its inverse and normal matrix, computed once when the
+Transform is created. Shapes store a Transform so they do not invert their matrix for every
+Ray, and use the normal matrix to transform normals correctly under non-uniform scaling.
+The inverse and normal matrix are None when the matrix is singular.
+With the 'wide' feature, a Transform of f64 also caches its inverse as a [`SimdMatrix4`].
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct Transform<P> {
+    matrix: Matrix4<P>,
+    inverse: Option<Matrix4<P>>,
+    normal_matrix: Option<Matrix3<P>>,
+    #[cfg(feature = "wide")]
+    simd_inverse: Option<SimdMatrix4>,
+}
+
+impl<P> Transform<P>
+where
+    P: Scalar,
+{
+    /// Creates a new [`Transform`], computing the inverse and normal matrix of 'matrix'.
+    pub fn new(matrix: Matrix4<P>) -> Self {
+        let inverse = matrix.try_inverse().ok();
+        Transform {
+            matrix,
+            inverse,
+            normal_matrix: matrix.normal_matrix().ok(),
+            #[cfg(feature = "wide")]
+            simd_inverse: inverse.and_then(P::simd_matrix),
+        }
+    }
+
+    /// Returns the forward transformation matrix.
+    pub fn matrix(&self) -> Matrix4<P> {
+        self.matrix
+    }
+
+    /// Returns the inverse matrix, or None if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix4<P>> {
+        self.inverse
+    }
+
+    /// Returns the normal matrix, see [`Matrix4Ops::normal_matrix`], or None if the matrix
+    /// is singular.
+    pub fn normal_matrix(&self) -> Option<Matrix3<P>> {
+        self.normal_matrix
+    }
+
+    /// Transforms a world space Ray to object space with the inverse matrix, or returns None
+    /// if the matrix is singular. With the 'wide' feature, Rays of f64 are transformed with
+    /// the cached [`SimdMatrix4`].
+    pub fn to_object_space(&self, ray: Ray<P>) -> Option<Ray<P>> {
+        #[cfg(feature = "wide")]
+        if let Some(simd_inverse) = &self.simd_inverse {
+            return P::simd_transform_ray(ray, simd_inverse);
+        }
+        Some(Ray::transform(ray, self.inverse?))
+    }
+
+    /// Transforms an object space normal to world space with the normal matrix, returning
+    /// it normalized, or None if the matrix is singular.
+    /// In debug builds, panics if the result contains NaN or infinite values, reporting
+    /// 'origin', e.g. the Shape the normal belongs to.
+    pub fn transform_normal(&self, normal: Vector3<P>, origin: Origin) -> Option<Vector3<P>>
+    where
+        P: Float,
+    {
+        let normal = (self.normal_matrix? * normal).normalized();
+        Some(validate_vector(normal, origin))
+    }
+}
+
+impl<P> Default for Transform<P>
+where
+    P: Scalar,
+{
+    fn default() -> Self {
+        Self::new(identity_matrix())
+    }
+}
+
+impl<P> From<Matrix4<P>> for Transform<P>
+where
+    P: Scalar,
+{
+    fn from(matrix: Matrix4<P>) -> Self {
+        Self::new(matrix)
+    }
+}
+
+// Comparison based on the forward matrix, from which the cached matrices derive.
+impl<P> PartialEq for Transform<P>
+where
+    P: Scalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for TransformStack and Transform types.
+
+use super::*;
+use crate::geometry::vector::*;
+use crate::shapes::ShapeId;
+
+#[test]
+// A new stack holds the identity and cannot be popped
+fn ut_transform_stack_initialization() {
+    let mut ts: TransformStack<f64> = TransformStack::new();
+    assert_eq!(ts.current(), Matrix4::<f64>::IDENTITY);
+    assert_eq!(ts.depth(), 0);
+    assert_eq!(ts.pop(), None);
+    println!("{}", ts);
+}
+
+#[test]
+// Nested transformations compose and unwind in order
+fn ut_transform_stack_nesting() {
+    let mut ts = TransformStack::new();
+    let p = Point3::new(1.0, 0.0, 0.0);
+
+    ts.push(Matrix4::from_translation(0.0, 5.0, 0.0));
+    ts.push(Matrix4::from_scale(2.0, 2.0, 2.0));
+    assert_eq!(ts.depth(), 2);
+    assert_eq!(ts.current() * p, Point3::new(2.0, 5.0, 0.0));
+
+    ts.pop();
+    ts.push(Matrix4::from_translation(3.0, 0.0, 0.0));
+    assert_eq!(ts.current() * p, Point3::new(4.0, 5.0, 0.0));
+
+    ts.pop();
+    ts.pop();
+    assert_eq!(ts.current(), Matrix4::<f64>::IDENTITY);
+}
+
+#[test]
+// A Transform caches the inverse and normal matrix of its matrix
+fn ut_transform_cached_inverse() {
+    let m = Matrix4::from_scale(1.0, 0.5, 1.0).rotate_z(std::f64::consts::PI / 5.0);
+    let t = Transform::new(m);
+    assert_eq!(t.matrix(), m);
+    assert_eq!(t.inverse(), Some(m.inverse()));
+    assert_eq!(t.normal_matrix(), Some(m.normal_matrix().unwrap()));
+    assert_eq!(
+        Transform::<f64>::default(),
+        Transform::from(Matrix4::<f64>::IDENTITY)
+    );
+
+    let singular = Transform::new(Matrix4::from_scale(0.0, 1.0, 1.0));
+    assert_eq!(singular.inverse(), None);
+    assert_eq!(singular.normal_matrix(), None);
+    assert_eq!(
+        singular.transform_normal(Vector3::y_coord(1.0), Origin::default()),
+        None
+    );
+}
+
+#[test]
+// Normals transformed with the normal matrix stay perpendicular under non-uniform scaling
+fn ut_transform_normal() {
+    let t = Transform::new(Matrix4::from_scale(1.0, 0.5, 1.0));
+    let h = 2f64.sqrt() / 2.0;
+    let n = t
+        .transform_normal(Vector3::new(0.0, h, -h), Origin::default())
+        .unwrap();
+    assert_eq!(n, Vector3::new(0.0, 0.89443, -0.44721));
+    assert_eq!(n.w, 0.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "shape: #5\tpixel: unknown")]
+// A degenerate normal is reported with the Shape it belongs to
+fn ut_transform_normal_validated() {
+    let t = Transform::new(Matrix4::from_scale(1.0, 0.5, 1.0));
+    t.transform_normal(Vector3::zero(), Origin::shape(ShapeId(5)));
+}
+
+#[test]
+// Rays are moved to object space with the inverse, with or without the SIMD matrix
+fn ut_transform_to_object_space() {
+    let m = Matrix4::from_rotation_y(0.5)
+        .scale(2.0, 1.0, 3.0)
+        .translate(1.0, 2.0, 3.0);
+    let ray = Ray::new(Point3::new(1.0, 2.0, -5.0), Vector3::new(0.0, 0.5, 1.0));
+    let object = Transform::new(m).to_object_space(ray).unwrap();
+    let expected = Ray::transform(ray, m.inverse());
+    assert_eq!(object.origin, expected.origin);
+    assert_eq!(object.direction, expected.direction);
+
+    let scaled = Transform::new(Matrix4::from_scale(2.0f32, 2.0, 2.0));
+    let ray = Ray::new(Point3::new(2.0f32, 4.0, 6.0), Vector3::z_coord(2.0));
+    let object = scaled.to_object_space(ray).unwrap();
+    assert_eq!(object.origin, Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(object.direction, Vector3::z_coord(1.0));
+
+    let singular = Transform::new(Matrix4::from_scale(0.0, 1.0, 1.0));
+    assert!(singular.to_object_space(ray.ray_to_f64()).is_none());
+}
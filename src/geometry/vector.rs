@@ -9,12 +9,13 @@
 /**
   Data structures and methods for Vector3 and Point3 computations.
 */
-use num::{cast::NumCast, Num};
+use num::{cast::NumCast, Float, Num};
 use std::fmt::Display;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::iter::Sum;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub};
 
 // Bring Geometry module constants into scope.
-use super::EPSILON;
+use super::{optics, scalar_from_f64, EPSILON};
 
 /// Provides Unit tests for Vector and Point types.
 #[cfg(test)]
@@ -23,6 +24,7 @@ mod tests;
 /// Type representing a geometric 3D Vector in its 'homogeneous' form with x, y, z, w components,
 /// and where 'w' stands for 'weight'
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector3<P> {
     /// Component on the X axis
     pub x: P,
@@ -37,6 +39,7 @@ pub struct Vector3<P> {
 /// Type representing a geometric 3D Point in its 'homogeneous' form with x, y, z components, and
 /// where 'W' stands for 'weight'
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point3<P> {
     /// Component on the X axis
     pub x: P,
@@ -48,6 +51,76 @@ pub struct Point3<P> {
     pub w: P,
 }
 
+/// Type representing a geometric 2D Vector with x, y components, e.g. a difference of
+/// UV texture coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct Vector2<P> {
+    /// Component on the X (or U) axis
+    pub x: P,
+    /// Component on the Y (or V) axis
+    pub y: P,
+}
+
+/// Type representing a geometric 2D Point with x, y components, e.g. UV texture coordinates
+/// or barycentric coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct Point2<P> {
+    /// Component on the X (or U) axis
+    pub x: P,
+    /// Component on the Y (or V) axis
+    pub y: P,
+}
+
+/**
+Point quantized to a grid of [`EPSILON`] spacing.
+Unlike Point3, which compares with an epsilon, HashablePoint3 has exact equality and can
+be hashed and ordered, so it can be used as a HashMap or BTreeMap key for vertex welding
+and spatial hash grids. Points closer than EPSILON usually share the same key, but two
+Points on either side of a grid boundary do not.
+*/
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HashablePoint3 {
+    /// Quantized component on the X axis
+    pub x: i64,
+    /// Quantized component on the Y axis
+    pub y: i64,
+    /// Quantized component on the Z axis
+    pub z: i64,
+}
+
+/// Ordered and hashable Point; the same type as [`HashablePoint3`].
+pub type OrderedPoint3 = HashablePoint3;
+
+/**
+Enum that selects the coordinates used to initialize a Vector or Point with [`CoordInit`].
+Omitted coordinates are '0', and an omitted 'weight' is the one of the type:
+'0' for a Vector and '1' for a Point.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis<P> {
+    /// X and Y coordinates.
+    XY(P, P),
+    /// X, Y and Z coordinates.
+    XYZ(P, P, P),
+    /// X, Y, Z coordinates and the 'weight'.
+    XYZW(P, P, P, P),
+}
+
+/// Returns the X and Y coordinates selector: [`Axis::XY`].
+pub fn xy<P>(x: P, y: P) -> Axis<P> {
+    Axis::XY(x, y)
+}
+
+/// Returns the X, Y and Z coordinates selector: [`Axis::XYZ`].
+pub fn xyz<P>(x: P, y: P, z: P) -> Axis<P> {
+    Axis::XYZ(x, y, z)
+}
+
+/// Returns the X, Y, Z coordinates and 'weight' selector: [`Axis::XYZW`].
+pub fn xyzw<P>(x: P, y: P, z: P, w: P) -> Axis<P> {
+    Axis::XYZW(x, y, z, w)
+}
+
 /// Trait that provides Vector and Point common initialization capabilities.
 pub trait Tuple<P>
 where
@@ -73,65 +146,243 @@ where
 
     /// Initialize a Vector or Point with all the coordinates with a value of '0'.
     fn zero() -> Self;
+
+    /// Linearly interpolate between 'a' (t = 0) and 'b' (t = 1).
+    /// Computed in f64 and rounded for integer coordinates. A coordinate that 't'
+    /// extrapolates out of the range of 'P' falls back to the nearest of 'a' or 'b'.
+    fn lerp(a: Self, b: Self, t: f64) -> Self
+    where
+        P: NumCast;
+
+    /// Return the smallest value of each coordinate of 'a' and 'b'.
+    fn min(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd;
+
+    /// Return the largest value of each coordinate of 'a' and 'b'.
+    fn max(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd;
+
+    /// Return each coordinate of 'v' restricted to the range given by 'lo' and 'hi'.
+    fn clamp(v: Self, lo: Self, hi: Self) -> Self
+    where
+        P: PartialOrd;
+
+    /// Return the absolute value of each coordinate of 'v'.
+    fn abs(v: Self) -> Self
+    where
+        P: PartialOrd;
+
+    /// Return the coordinate value at 'index', using the same indices as min_component
+    /// and max_component: 0 for X, 1 for Y, 2 for Z and 3 for W.
+    fn this(&self, index: i8) -> Option<P>;
+
+    /// Return the coordinate value named 'name': 'x', 'y', 'z' or 'w'.
+    fn this_name(&self, name: char) -> Option<P> {
+        match name {
+            'x' => self.this(0),
+            'y' => self.this(1),
+            'z' => self.this(2),
+            'w' => self.this(3),
+            _ => None,
+        }
+    }
+
+    /// Return the information of the smallest X, Y or Z coordinate value.
+    fn min_component(&self) -> (i8, char, P)
+    where
+        P: PartialOrd,
+    {
+        let (x, y, z) = (
+            self.this(0).unwrap(),
+            self.this(1).unwrap(),
+            self.this(2).unwrap(),
+        );
+        if x <= y && x <= z {
+            (0, 'x', x)
+        } else if y <= z {
+            (1, 'y', y)
+        } else {
+            (2, 'z', z)
+        }
+    }
+
+    /// Return the information of the largest X, Y or Z coordinate value.
+    fn max_component(&self) -> (i8, char, P)
+    where
+        P: PartialOrd,
+    {
+        let (x, y, z) = (
+            self.this(0).unwrap(),
+            self.this(1).unwrap(),
+            self.this(2).unwrap(),
+        );
+        if x >= y && x >= z {
+            (0, 'x', x)
+        } else if y >= z {
+            (1, 'y', y)
+        } else {
+            (2, 'z', z)
+        }
+    }
+}
+
+// Smallest of two coordinates.
+fn min_coord<P: PartialOrd>(a: P, b: P) -> P {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+// Largest of two coordinates.
+fn max_coord<P: PartialOrd>(a: P, b: P) -> P {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+// Absolute value of a coordinate.
+fn abs_coord<P: Num + PartialOrd>(a: P) -> P {
+    if a < P::zero() {
+        P::zero() - a
+    } else {
+        a
+    }
+}
+
+// Linear interpolation of a single coordinate.
+fn lerp_coord<P: NumCast + Copy>(a: P, b: P, t: f64) -> P {
+    let value = match (a.to_f64(), b.to_f64()) {
+        (Some(fa), Some(fb)) => scalar_from_f64(fa + (fb - fa) * t),
+        _ => None,
+    };
+    // Only an extrapolation can leave the range of 'P': keep the endpoint on its side.
+    value.unwrap_or(if t > 1.0 { b } else { a })
 }
 
 /// Trait that provides Vector capabilities.
+/// The metric operations -magnitude, normalization, dot and cross products, angles,
+/// projections and optics- require a floating point scalar type; integer Vectors only
+/// support construction and component-wise arithmetic.
 pub trait Vector<P>: Tuple<P>
 where
     P: Copy + Num,
 {
     /// Initialize a Vector with the Z coordinate with a value of '-1'.
+    #[deprecated(
+        note = "use the -Vector3::UNIT_Z associated const, or Tuple::z_coord(-num::one()) in generic code"
+    )]
     fn back() -> Self
     where
         P: Neg + Neg<Output = P>;
 
     /// Initialize a Vector with the Y coordinate with a value of '-1'.
+    #[deprecated(
+        note = "use the -Vector3::UNIT_Y associated const, or Tuple::y_coord(-num::one()) in generic code"
+    )]
     fn down() -> Self
     where
         P: Neg + Neg<Output = P>;
 
     /// Initialize a Vector with the Z coordinate with a value of '1'.
+    #[deprecated(
+        note = "use the Vector3::UNIT_Z associated const, or Tuple::z_coord(num::one()) in generic code"
+    )]
     fn forward() -> Self;
 
     /// Initialize a Vector with the X coordinate with a value of '-1'.
+    #[deprecated(
+        note = "use the -Vector3::UNIT_X associated const, or Tuple::x_coord(-num::one()) in generic code"
+    )]
     fn left() -> Self
     where
         P: Neg + Neg<Output = P>;
 
     /// Initialize a Vector with the X coordinate with a value of '1'.
+    #[deprecated(
+        note = "use the Vector3::UNIT_X associated const, or Tuple::x_coord(num::one()) in generic code"
+    )]
     fn right() -> Self;
 
     /// Initialize a Vector with the Y coordinate with a value of '1'.
+    #[deprecated(
+        note = "use the Vector3::UNIT_Y associated const, or Tuple::y_coord(num::one()) in generic code"
+    )]
     fn up() -> Self;
 
     /// Normalize a Vector by dividing it by its Magnitude.
+    /// A Vector of magnitude '0' produces NaN coordinates; use try_normalized when the
+    /// Vector may be degenerate.
     fn normalized(&mut self) -> Self
     where
-        P: NumCast;
+        P: Float;
 
-    /// Return the information of the smallest coordinate value.
-    fn min_component(&self) -> (i8, char, P)
+    /// Normalize a Vector, returning None for a Vector of magnitude '0' or with
+    /// non-finite coordinates.
+    fn try_normalized(&self) -> Option<Self>
     where
-        P: PartialOrd;
+        P: Float,
+        Self: Sized;
 
-    /// Return the information of the largest coordinate value.
-    fn max_component(&self) -> (i8, char, P)
+    /// Return true if the magnitude of the Vector is '1' within EPSILON.
+    fn is_normalized(&self) -> bool
     where
-        P: PartialOrd;
+        P: Float;
 
     /// Calculate the magnitude of a Vector.
     fn magnitude(&self) -> P
     where
-        P: NumCast;
+        P: Float;
 
     /// Calculate the Cross product between two Vectors.
-    fn cross(lhs: Vector3<P>, rhs: Vector3<P>) -> Vector3<P>;
+    fn cross(lhs: Vector3<P>, rhs: Vector3<P>) -> Vector3<P>
+    where
+        P: Float;
 
     /// Calculate the Dot product between two Vectors.
-    fn dot(lhs: Vector3<P>, rhs: Vector3<P>) -> P;
+    fn dot(lhs: Vector3<P>, rhs: Vector3<P>) -> P
+    where
+        P: Float;
+
+    /// Calculate the angle in radians between two Vectors, in [0, PI].
+    /// Returns '0' if either Vector has a magnitude of '0'.
+    fn angle_between(a: Vector3<P>, b: Vector3<P>) -> P
+    where
+        P: Float;
+
+    /// Calculate the projection of a Vector onto another one.
+    /// Returns a Vector of '0' if 'onto' has a magnitude of '0'.
+    fn project(a: Vector3<P>, onto: Vector3<P>) -> Vector3<P>
+    where
+        P: Float;
+
+    /// Calculate the rejection of a Vector from another one: the component of 'a'
+    /// perpendicular to 'from', so that project(a, from) + reject(a, from) = a.
+    fn reject(a: Vector3<P>, from: Vector3<P>) -> Vector3<P>
+    where
+        P: Float;
+
+    /// Calculate the reflection of an incoming Vector about a surface normal.
+    fn reflect(incoming: Vector3<P>, normal: Vector3<P>) -> Vector3<P>
+    where
+        P: Float;
+
+    /// Calculate the refraction of an incident Vector through a surface normal following
+    /// Snell's law, where 'eta_ratio' is n1 / n2. Returns None on total internal reflection.
+    fn refract(incident: Vector3<P>, normal: Vector3<P>, eta_ratio: P) -> Option<Vector3<P>>
+    where
+        P: Float;
 
     /// Returns a Vector of type f64.
     fn v_to_f64(self) -> Vector3<f64>;
+
+    /// Returns the Point at the tip of the Vector placed at the origin.
+    fn to_point(self) -> Point3<P>;
 }
 
 /// Trait that provides Point capabilities.
@@ -140,10 +391,24 @@ where
     P: Copy + Num,
 {
     /// Set a Point with all its coordinates with a value of '0'.
+    #[deprecated(note = "use the Point3::ORIGIN associated const, or Tuple::zero in generic code")]
     fn origin(&mut self) -> Self;
 
     /// Returns a Point of type f64.
     fn p_to_f64(self) -> Point3<f64>;
+
+    /// Returns the Vector from the origin to the Point.
+    fn to_vector(self) -> Vector3<P>;
+}
+
+/// Trait that initializes a Vector or Point from an [`Axis`] coordinates selector, e.g.
+/// 'Point3::from_axis(xyzw(1.0, 2.0, 3.0, 1.0))'.
+pub trait CoordInit<P>: Tuple<P>
+where
+    P: Copy + Num,
+{
+    /// Initialize a Vector or Point with the coordinates selected by 'axis'.
+    fn from_axis(axis: Axis<P>) -> Self;
 }
 
 // Implementation of the Tuple Supertrait for Vector.
@@ -213,6 +478,71 @@ where
             w: num::zero(),
         }
     }
+
+    fn lerp(a: Self, b: Self, t: f64) -> Self
+    where
+        P: NumCast,
+    {
+        Vector3 {
+            x: lerp_coord(a.x, b.x, t),
+            y: lerp_coord(a.y, b.y, t),
+            z: lerp_coord(a.z, b.z, t),
+            w: lerp_coord(a.w, b.w, t),
+        }
+    }
+
+    fn min(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Vector3 {
+            x: min_coord(a.x, b.x),
+            y: min_coord(a.y, b.y),
+            z: min_coord(a.z, b.z),
+            w: a.w,
+        }
+    }
+
+    fn max(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Vector3 {
+            x: max_coord(a.x, b.x),
+            y: max_coord(a.y, b.y),
+            z: max_coord(a.z, b.z),
+            w: a.w,
+        }
+    }
+
+    fn clamp(v: Self, lo: Self, hi: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Vector3::min(Vector3::max(v, lo), hi)
+    }
+
+    fn abs(v: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Vector3 {
+            x: abs_coord(v.x),
+            y: abs_coord(v.y),
+            z: abs_coord(v.z),
+            w: v.w,
+        }
+    }
+
+    fn this(&self, index: i8) -> Option<P> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => Some(self.z),
+            3 => Some(self.w),
+            _ => None,
+        }
+    }
 }
 
 // Implementation of the Tuple Supertrait for Point.
@@ -283,6 +613,71 @@ where
             w: num::one(),
         }
     }
+
+    fn lerp(a: Self, b: Self, t: f64) -> Self
+    where
+        P: NumCast,
+    {
+        Point3 {
+            x: lerp_coord(a.x, b.x, t),
+            y: lerp_coord(a.y, b.y, t),
+            z: lerp_coord(a.z, b.z, t),
+            w: lerp_coord(a.w, b.w, t),
+        }
+    }
+
+    fn min(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Point3 {
+            x: min_coord(a.x, b.x),
+            y: min_coord(a.y, b.y),
+            z: min_coord(a.z, b.z),
+            w: a.w,
+        }
+    }
+
+    fn max(a: Self, b: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Point3 {
+            x: max_coord(a.x, b.x),
+            y: max_coord(a.y, b.y),
+            z: max_coord(a.z, b.z),
+            w: a.w,
+        }
+    }
+
+    fn clamp(v: Self, lo: Self, hi: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Point3::min(Point3::max(v, lo), hi)
+    }
+
+    fn abs(v: Self) -> Self
+    where
+        P: PartialOrd,
+    {
+        Point3 {
+            x: abs_coord(v.x),
+            y: abs_coord(v.y),
+            z: abs_coord(v.z),
+            w: v.w,
+        }
+    }
+
+    fn this(&self, index: i8) -> Option<P> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => Some(self.z),
+            3 => Some(self.w),
+            _ => None,
+        }
+    }
 }
 
 // Implementation of the Point subtrait capabilities.
@@ -307,6 +702,10 @@ where
             w: self.w.to_f64().unwrap(),
         }
     }
+
+    fn to_vector(self) -> Vector3<P> {
+        Vector3::new(self.x, self.y, self.z)
+    }
 }
 
 // Implemenation of the Vector subtrait capabilitites.
@@ -379,7 +778,7 @@ where
 
     fn normalized(&mut self) -> Self
     where
-        P: NumCast,
+        P: Float,
     {
         let mag = self.magnitude();
         Self {
@@ -390,46 +789,46 @@ where
         }
     }
 
-    fn min_component(&self) -> (i8, char, P)
+    fn try_normalized(&self) -> Option<Self>
     where
-        P: PartialOrd,
+        P: Float,
     {
-        if self.x <= self.y && self.x <= self.z {
-            (0, 'x', self.x)
-        } else if self.y <= self.z {
-            (1, 'y', self.y)
+        let mag = self.magnitude();
+        if mag.to_f64()? < EPSILON || !mag.is_finite() {
+            return None;
+        }
+        let unit = Vector3 {
+            x: self.x / mag,
+            y: self.y / mag,
+            z: self.z / mag,
+            w: self.w,
+        };
+        if unit.is_normalized() {
+            Some(unit)
         } else {
-            (2, 'z', self.z)
+            None
         }
     }
 
-    fn max_component(&self) -> (i8, char, P)
+    fn is_normalized(&self) -> bool
     where
-        P: PartialOrd,
+        P: Float,
     {
-        if self.x >= self.y && self.x >= self.z {
-            (0, 'x', self.x)
-        } else if self.y >= self.z {
-            (1, 'y', self.y)
-        } else {
-            (2, 'z', self.z)
-        }
+        let error = (self.magnitude() - P::one()).abs();
+        error.to_f64().map_or(false, |e| e < EPSILON)
     }
 
     fn magnitude(&self) -> P
     where
-        P: NumCast,
+        P: Float,
     {
-        P::from(
-            (self.x * self.x + self.y * self.y + self.z * self.z)
-                .to_f64()
-                .unwrap()
-                .sqrt(),
-        )
-        .unwrap()
+        Vector3::dot(*self, *self).sqrt()
     }
 
-    fn cross(lhs: Vector3<P>, rhs: Vector3<P>) -> Vector3<P> {
+    fn cross(lhs: Vector3<P>, rhs: Vector3<P>) -> Vector3<P>
+    where
+        P: Float,
+    {
         Vector3 {
             x: lhs.y * rhs.z - lhs.z * rhs.y,
             y: lhs.z * rhs.x - lhs.x * rhs.z,
@@ -438,10 +837,59 @@ where
         }
     }
 
-    fn dot(lhs: Vector3<P>, rhs: Vector3<P>) -> P {
+    fn dot(lhs: Vector3<P>, rhs: Vector3<P>) -> P
+    where
+        P: Float,
+    {
         lhs.x * rhs.x + lhs.y * rhs.y + lhs.z * rhs.z
     }
 
+    fn angle_between(a: Vector3<P>, b: Vector3<P>) -> P
+    where
+        P: Float,
+    {
+        let magnitudes = a.magnitude() * b.magnitude();
+        if magnitudes == P::zero() {
+            return P::zero();
+        }
+        let cos = (Vector3::dot(a, b) / magnitudes)
+            .max(-P::one())
+            .min(P::one());
+        cos.acos()
+    }
+
+    fn project(a: Vector3<P>, onto: Vector3<P>) -> Vector3<P>
+    where
+        P: Float,
+    {
+        let length_squared = Vector3::dot(onto, onto);
+        if length_squared == P::zero() {
+            return Vector3::zero();
+        }
+        onto * (Vector3::dot(a, onto) / length_squared)
+    }
+
+    fn reject(a: Vector3<P>, from: Vector3<P>) -> Vector3<P>
+    where
+        P: Float,
+    {
+        a - Vector3::project(a, from)
+    }
+
+    fn reflect(incoming: Vector3<P>, normal: Vector3<P>) -> Vector3<P>
+    where
+        P: Float,
+    {
+        optics::reflect(incoming, normal)
+    }
+
+    fn refract(incident: Vector3<P>, normal: Vector3<P>, eta_ratio: P) -> Option<Vector3<P>>
+    where
+        P: Float,
+    {
+        optics::refract(incident, normal, eta_ratio)
+    }
+
     fn v_to_f64(self) -> Vector3<f64> {
         Vector3 {
             x: self.x.to_f64().unwrap(),
@@ -450,27 +898,45 @@ where
             w: self.w.to_f64().unwrap(),
         }
     }
+
+    fn to_point(self) -> Point3<P> {
+        Point3::new(self.x, self.y, self.z)
+    }
+}
+
+// Associated consts of the Vector and Point types with floating point coordinates.
+macro_rules! impl_float_consts {
+    ($($t:ty),*) => {
+        $(
+            impl Vector3<$t> {
+                /// Unit Vector on the X axis.
+                pub const UNIT_X: Vector3<$t> = Vector3 { x: 1.0, y: 0.0, z: 0.0, w: 0.0 };
+                /// Unit Vector on the Y axis.
+                pub const UNIT_Y: Vector3<$t> = Vector3 { x: 0.0, y: 1.0, z: 0.0, w: 0.0 };
+                /// Unit Vector on the Z axis.
+                pub const UNIT_Z: Vector3<$t> = Vector3 { x: 0.0, y: 0.0, z: 1.0, w: 0.0 };
+            }
+
+            impl Point3<$t> {
+                /// Point at the origin of the coordinate system.
+                pub const ORIGIN: Point3<$t> = Point3 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+            }
+        )*
+    };
 }
 
+impl_float_consts!(f32, f64);
+
 // Implementation of the Partial Equivalence trait for Vector.
 impl<P> PartialEq for Vector3<P>
 where
     P: Num + NumCast,
 {
     fn eq(&self, other: &Self) -> bool {
-        if (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
+        (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
             && (self.y.to_f64().unwrap() - other.y.to_f64().unwrap()).abs() < EPSILON
             && (self.z.to_f64().unwrap() - other.z.to_f64().unwrap()).abs() < EPSILON
             && (self.w.to_f64().unwrap() - other.w.to_f64().unwrap()).abs() < EPSILON
-        {
-            true
-        } else {
-            false
-        }
-    }
-
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
     }
 }
 
@@ -480,19 +946,10 @@ where
     P: Num + NumCast,
 {
     fn eq(&self, other: &Self) -> bool {
-        if (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
+        (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
             && (self.y.to_f64().unwrap() - other.y.to_f64().unwrap()).abs() < EPSILON
             && (self.z.to_f64().unwrap() - other.z.to_f64().unwrap()).abs() < EPSILON
             && (self.w.to_f64().unwrap() - other.w.to_f64().unwrap()).abs() < EPSILON
-        {
-            true
-        } else {
-            false
-        }
-    }
-
-    fn ne(&self, other: &Self) -> bool {
-        !self.eq(other)
     }
 }
 
@@ -554,6 +1011,210 @@ where
     }
 }
 
+// Implementation of the From trait for Vector from a tuple of x, y, z coordinates.
+impl<P> From<(P, P, P)> for Vector3<P>
+where
+    P: Copy + Num,
+{
+    fn from((x, y, z): (P, P, P)) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+// Implementation of the From trait for Vector from an array of x, y, z coordinates.
+impl<P> From<[P; 3]> for Vector3<P>
+where
+    P: Copy + Num,
+{
+    fn from([x, y, z]: [P; 3]) -> Self {
+        Vector3::new(x, y, z)
+    }
+}
+
+// Implementation of the From trait for Point from a tuple of x, y, z coordinates.
+impl<P> From<(P, P, P)> for Point3<P>
+where
+    P: Copy + Num,
+{
+    fn from((x, y, z): (P, P, P)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+// Implementation of the From trait for Point from an array of x, y, z coordinates.
+impl<P> From<[P; 3]> for Point3<P>
+where
+    P: Copy + Num,
+{
+    fn from([x, y, z]: [P; 3]) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+// Implementation of the From trait for tuples and arrays of x, y, z coordinates from Vector
+// and Point, dropping the 'weight'.
+macro_rules! impl_into_coords {
+    ($($t:ident),*) => {
+        $(
+            impl<P> From<$t<P>> for (P, P, P) {
+                fn from(v: $t<P>) -> Self {
+                    (v.x, v.y, v.z)
+                }
+            }
+
+            impl<P> From<$t<P>> for [P; 3] {
+                fn from(v: $t<P>) -> Self {
+                    [v.x, v.y, v.z]
+                }
+            }
+        )*
+    };
+}
+
+impl_into_coords!(Vector3, Point3);
+
+// Implementation of CoordInit, and of the From trait from an Axis selector, for Vector and
+// Point. The 'weight' set by Tuple::new is kept unless the selector overrides it.
+macro_rules! impl_coord_init {
+    ($($t:ident),+) => {
+        $(
+            impl<P> CoordInit<P> for $t<P>
+            where
+                P: Copy + Num,
+            {
+                fn from_axis(axis: Axis<P>) -> Self {
+                    match axis {
+                        Axis::XY(x, y) => $t::new(x, y, num::zero()),
+                        Axis::XYZ(x, y, z) => $t::new(x, y, z),
+                        Axis::XYZW(x, y, z, w) => $t { x, y, z, w },
+                    }
+                }
+            }
+
+            impl<P> From<Axis<P>> for $t<P>
+            where
+                P: Copy + Num,
+            {
+                fn from(axis: Axis<P>) -> Self {
+                    $t::from_axis(axis)
+                }
+            }
+        )+
+    };
+}
+
+impl_coord_init!(Vector3, Point3);
+
+impl<P> From<Point3<P>> for HashablePoint3
+where
+    P: Copy + Num + NumCast,
+{
+    /// Rounds every coordinate to the nearest multiple of EPSILON.
+    fn from(p: Point3<P>) -> Self {
+        let p = p.p_to_f64();
+        let quantize = |v: f64| (v / EPSILON).round() as i64;
+        HashablePoint3 {
+            x: quantize(p.x),
+            y: quantize(p.y),
+            z: quantize(p.z),
+        }
+    }
+}
+
+impl From<HashablePoint3> for Point3<f64> {
+    fn from(k: HashablePoint3) -> Self {
+        Point3::new(
+            k.x as f64 * EPSILON,
+            k.y as f64 * EPSILON,
+            k.z as f64 * EPSILON,
+        )
+    }
+}
+
+// Implementation of the approx traits for Vector and Point, comparing every coordinate with
+// the tolerances of the scalar type.
+#[cfg(feature = "approx")]
+macro_rules! impl_approx {
+    ($($t:ident),*) => {
+        $(
+            impl<P> approx::AbsDiffEq for $t<P>
+            where
+                P: Num + NumCast + approx::AbsDiffEq,
+                P::Epsilon: Copy,
+            {
+                type Epsilon = P::Epsilon;
+
+                fn default_epsilon() -> P::Epsilon {
+                    P::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: P::Epsilon) -> bool {
+                    self.x.abs_diff_eq(&other.x, epsilon)
+                        && self.y.abs_diff_eq(&other.y, epsilon)
+                        && self.z.abs_diff_eq(&other.z, epsilon)
+                        && self.w.abs_diff_eq(&other.w, epsilon)
+                }
+            }
+
+            impl<P> approx::RelativeEq for $t<P>
+            where
+                P: Num + NumCast + approx::RelativeEq,
+                P::Epsilon: Copy,
+            {
+                fn default_max_relative() -> P::Epsilon {
+                    P::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: P::Epsilon, max_relative: P::Epsilon) -> bool {
+                    self.x.relative_eq(&other.x, epsilon, max_relative)
+                        && self.y.relative_eq(&other.y, epsilon, max_relative)
+                        && self.z.relative_eq(&other.z, epsilon, max_relative)
+                        && self.w.relative_eq(&other.w, epsilon, max_relative)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "approx")]
+impl_approx!(Vector3, Point3);
+
+// Implementation of the Index and IndexMut traits for Vector and Point, with the same
+// indices as Tuple::this: 0 for X, 1 for Y, 2 for Z and 3 for W.
+macro_rules! impl_index {
+    ($($t:ident),*) => {
+        $(
+            impl<P> Index<usize> for $t<P> {
+                type Output = P;
+
+                fn index(&self, index: usize) -> &P {
+                    match index {
+                        0 => &self.x,
+                        1 => &self.y,
+                        2 => &self.z,
+                        3 => &self.w,
+                        _ => panic!("{} index out of bounds: {}", stringify!($t), index),
+                    }
+                }
+            }
+
+            impl<P> IndexMut<usize> for $t<P> {
+                fn index_mut(&mut self, index: usize) -> &mut P {
+                    match index {
+                        0 => &mut self.x,
+                        1 => &mut self.y,
+                        2 => &mut self.z,
+                        3 => &mut self.w,
+                        _ => panic!("{} index out of bounds: {}", stringify!($t), index),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_index!(Vector3, Point3);
+
 // ---- Operator Overloading Implementations for Vector and Point.
 
 // Vector + Vector
@@ -759,3 +1420,323 @@ where
         }
     }
 }
+
+// Sum of Vectors: the 'weight' stays '0'.
+impl<P> Sum for Vector3<P>
+where
+    P: Copy + Num,
+{
+    fn sum<I: Iterator<Item = Vector3<P>>>(iter: I) -> Self {
+        iter.fold(Vector3::all(num::zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, P> Sum<&'a Vector3<P>> for Vector3<P>
+where
+    P: Copy + Num,
+{
+    fn sum<I: Iterator<Item = &'a Vector3<P>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/**
+Returns the centroid -average position- of a collection of Points, or None if it is empty.
+Points cannot be summed directly, as their 'weight' would add up too, so the coordinates
+are averaged in f64. For integer coordinates the centroid is rounded to the nearest integer.
+*/
+pub fn centroid<P>(points: &[Point3<P>]) -> Option<Point3<P>>
+where
+    P: Copy + Num + NumCast,
+{
+    if points.is_empty() {
+        return None;
+    }
+    let sum: Vector3<f64> = points.iter().map(|p| p.p_to_f64().to_vector()).sum();
+    let mean = sum / points.len() as f64;
+    Some(Point3::new(
+        scalar_from_f64(mean.x)?,
+        scalar_from_f64(mean.y)?,
+        scalar_from_f64(mean.z)?,
+    ))
+}
+
+// ---- Vector2 and Point2 Implementations.
+
+impl<P> Vector2<P>
+where
+    P: Copy + Num,
+{
+    /// Initialize a Vector2 with each axis with a separate user-defined value.
+    pub fn new(x: P, y: P) -> Self {
+        Vector2 { x, y }
+    }
+
+    /// Calculate the Dot product between two Vector2.
+    pub fn dot(lhs: Vector2<P>, rhs: Vector2<P>) -> P
+    where
+        P: Float,
+    {
+        lhs.x * rhs.x + lhs.y * rhs.y
+    }
+}
+
+impl<P> Point2<P>
+where
+    P: Copy + Num,
+{
+    /// Initialize a Point2 with each axis with a separate user-defined value.
+    pub fn new(x: P, y: P) -> Self {
+        Point2 { x, y }
+    }
+}
+
+// Implementation of the Partial Equivalence trait for Vector2.
+impl<P> PartialEq for Vector2<P>
+where
+    P: Num + NumCast,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
+            && (self.y.to_f64().unwrap() - other.y.to_f64().unwrap()).abs() < EPSILON
+    }
+}
+
+// Implementation of the Partial Equivalence trait for Point2.
+impl<P> PartialEq for Point2<P>
+where
+    P: Num + NumCast,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.x.to_f64().unwrap() - other.x.to_f64().unwrap()).abs() < EPSILON
+            && (self.y.to_f64().unwrap() - other.y.to_f64().unwrap()).abs() < EPSILON
+    }
+}
+
+// Implementation of the Display trait for Vector2.
+impl<P> Display for Vector2<P>
+where
+    P: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("v: [{:^8.2},{:^8.2}]", self.x, self.y);
+        f.write_str(&s)
+    }
+}
+
+// Implementation of the Display trait for Point2.
+impl<P> Display for Point2<P>
+where
+    P: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("p: [{:^8.2},{:^8.2}]", self.x, self.y);
+        f.write_str(&s)
+    }
+}
+
+// Implementation of the Default trait for Vector2.
+impl<P> Default for Vector2<P>
+where
+    P: Num,
+{
+    fn default() -> Self {
+        Vector2 {
+            x: num::zero(),
+            y: num::zero(),
+        }
+    }
+}
+
+// Implementation of the Default trait for Point2.
+impl<P> Default for Point2<P>
+where
+    P: Num,
+{
+    fn default() -> Self {
+        Point2 {
+            x: num::zero(),
+            y: num::zero(),
+        }
+    }
+}
+
+// Vector2 + Vector2
+impl<P> Add for Vector2<P>
+where
+    P: Num,
+{
+    type Output = Vector2<P>;
+
+    fn add(self, rhs: Vector2<P>) -> Self::Output {
+        Vector2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+// Vector2 + Point2
+impl<P> Add<Point2<P>> for Vector2<P>
+where
+    P: Num,
+{
+    type Output = Point2<P>;
+
+    fn add(self, rhs: Point2<P>) -> Self::Output {
+        Point2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+/// Point2 + Vector2
+impl<P> Add<Vector2<P>> for Point2<P>
+where
+    P: Num,
+{
+    type Output = Point2<P>;
+
+    fn add(self, rhs: Vector2<P>) -> Self::Output {
+        Point2 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+/// Vector2 - Vector2
+impl<P> Sub<Vector2<P>> for Vector2<P>
+where
+    P: Num,
+{
+    type Output = Vector2<P>;
+
+    fn sub(self, rhs: Vector2<P>) -> Self::Output {
+        Vector2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+/// Point2 - Point2
+impl<P> Sub<Point2<P>> for Point2<P>
+where
+    P: Num,
+{
+    type Output = Vector2<P>;
+
+    fn sub(self, rhs: Point2<P>) -> Self::Output {
+        Vector2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+/// Point2 - Vector2
+impl<P> Sub<Vector2<P>> for Point2<P>
+where
+    P: Num,
+{
+    type Output = Point2<P>;
+
+    fn sub(self, rhs: Vector2<P>) -> Self::Output {
+        Point2 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+/// -Vector2
+impl<P> Neg for Vector2<P>
+where
+    P: Num + Neg + Neg<Output = P>,
+{
+    type Output = Vector2<P>;
+
+    fn neg(self) -> Self::Output {
+        Vector2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// -Point2
+impl<P> Neg for Point2<P>
+where
+    P: Num + Neg + Neg<Output = P>,
+{
+    type Output = Point2<P>;
+
+    fn neg(self) -> Self::Output {
+        Point2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// Vector2 * Scalar
+impl<P> Mul<P> for Vector2<P>
+where
+    P: Copy + Num,
+{
+    type Output = Vector2<P>;
+
+    fn mul(self, rhs: P) -> Self::Output {
+        Vector2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Point2 * Scalar
+impl<P> Mul<P> for Point2<P>
+where
+    P: Copy + Num,
+{
+    type Output = Point2<P>;
+
+    fn mul(self, rhs: P) -> Self::Output {
+        Point2 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Vector2 / Scalar
+impl<P> Div<P> for Vector2<P>
+where
+    P: Copy + Num,
+{
+    type Output = Vector2<P>;
+
+    fn div(self, rhs: P) -> Self::Output {
+        Vector2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+/// Point2 / Scalar
+impl<P> Div<P> for Point2<P>
+where
+    P: Copy + Num,
+{
+    type Output = Point2<P>;
+
+    fn div(self, rhs: P) -> Self::Output {
+        Point2 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
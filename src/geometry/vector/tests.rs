@@ -9,6 +9,7 @@
 //Unit testing for Vector3 and Point3 types
 use super::*;
 
+#[allow(deprecated)]
 #[test]
 // This test validates the construction of the Vector3 and Point3 types
 fn ut_vector_and_point_construction_integrity() {
@@ -199,11 +200,11 @@ fn ut_vector_simulate_rocket_lauch() {
     };
 
     let env = Environment {
-        gravity: Vector3::down() / 10f64,
-        wind: Vector3::left() / 100f64,
+        gravity: Vector3::y_coord(-1.0) / 10f64,
+        wind: Vector3::x_coord(-1.0) / 100f64,
     };
 
-    fn tick<'a, 'b>(env: &'a Environment, proj: &'b mut Projectile) -> &'b mut Projectile {
+    fn tick<'b>(env: &Environment, proj: &'b mut Projectile) -> &'b mut Projectile {
         proj.position = proj.position + proj.velocity;
         proj.velocity = proj.velocity + env.gravity + env.wind;
         proj
@@ -225,3 +226,283 @@ fn ut_vector_simulate_rocket_lauch() {
     }
     println!("========================== End");
 }
+
+#[test]
+// Vectors and Points are built from tuples or arrays and their components are accessed
+// by index or by name
+fn ut_vector_from_and_component_access() {
+    let v: Vector3<f64> = (1.0, 2.0, 3.0).into();
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from([1.0, 2.0, 3.0]), v);
+    assert_eq!(Point3::from((1, 2, 3)), Point3::new(1, 2, 3));
+    assert_eq!(Point3::from([1, 2, 3]).w, 1);
+
+    assert_eq!(v.this(1), Some(2.0));
+    assert_eq!(v.this(3), Some(0.0));
+    assert_eq!(v.this(4), None);
+    assert_eq!(v.this_name('z'), Some(3.0));
+    assert_eq!(v.this_name('q'), None);
+    let (index, name, value) = v.max_component();
+    assert_eq!(v.this(index), Some(value));
+    assert_eq!(v.this_name(name), Some(value));
+}
+
+#[test]
+// Vectors and Points are initialized from an Axis coordinates selector
+fn ut_vector_point_coord_init() {
+    assert_eq!(
+        Vector3::from_axis(xyz(1.0, 2.0, 3.0)),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(Point3::from_axis(xy(1, 2)), Point3::new(1, 2, 0));
+    assert_eq!(Point3::from_axis(xy(1, 2)).w, 1);
+    let p = Point3::from_axis(xyzw(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(p, Point3::new(1.0, 2.0, 3.0));
+    let v: Vector3<i32> = Axis::XYZW(1, 2, 3, 0).into();
+    assert_eq!(v, Vector3::new(1, 2, 3));
+    assert_eq!(Vector3::from(Axis::XYZ(0, 0, 1)).w, 0);
+}
+
+#[test]
+// Vectors are summed with iter().sum() and Points averaged with centroid()
+fn ut_vector_sum_and_centroid() {
+    let vs = [
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-1.0, 0.0, 1.0),
+        Vector3::new(0.0, 1.0, 1.0),
+    ];
+    let total: Vector3<f64> = vs.iter().sum();
+    assert_eq!(total, Vector3::new(0.0, 3.0, 5.0));
+    assert_eq!(total.w, 0.0);
+    assert_eq!(vs.into_iter().sum::<Vector3<f64>>(), total);
+
+    let ps = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(1.0, 3.0, 0.0),
+    ];
+    let c = centroid(&ps).unwrap();
+    assert_eq!(c, Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(c.w, 1.0);
+    assert_eq!(
+        centroid(&[Point3::new(0, 0, 0), Point3::new(3, 1, 0)]),
+        Some(Point3::new(2, 1, 0))
+    );
+    assert_eq!(centroid::<f64>(&[]), None);
+}
+
+#[test]
+// Points within EPSILON are welded together as HashMap and BTreeMap keys
+fn ut_hashable_point_keys() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let a = Point3::new(1.0, 2.0, 3.0);
+    let b = Point3::new(1.0 + EPSILON / 10.0, 2.0, 3.0 - EPSILON / 10.0);
+    let c = Point3::new(1.0, 2.0, 3.5);
+
+    let mut welded: HashMap<HashablePoint3, usize> = HashMap::new();
+    for (i, p) in [a, b, c].into_iter().enumerate() {
+        welded.entry(p.into()).or_insert(i);
+    }
+    assert_eq!(welded.len(), 2);
+    assert_eq!(welded[&HashablePoint3::from(b)], 0);
+
+    let ordered: BTreeSet<OrderedPoint3> = [c, a].into_iter().map(Into::into).collect();
+    assert_eq!(ordered.iter().next(), Some(&HashablePoint3::from(a)));
+    assert_eq!(Point3::from(HashablePoint3::from(c)), c);
+    assert_eq!(HashablePoint3::from(Point3::new(1, -2, 0)).y, -20000);
+}
+
+#[test]
+// Points expose the same component queries as Vectors, so generic code handles both
+fn ut_point_component_access() {
+    fn largest_axis<P: Copy + Num + PartialOrd, T: Tuple<P>>(t: T) -> char {
+        t.max_component().1
+    }
+
+    let p = Point3::new(4.0, -2.0, 3.0);
+    assert_eq!(p.this(3), Some(1.0));
+    assert_eq!(p.this_name('w'), Some(1.0));
+    assert_eq!(p.this_name('y'), Some(-2.0));
+    assert_eq!(p.min_component(), (1, 'y', -2.0));
+    assert_eq!(p.max_component(), (0, 'x', 4.0));
+    assert_eq!(largest_axis(p), 'x');
+    assert_eq!(largest_axis(Vector3::new(1, 2, 5)), 'z');
+}
+
+#[test]
+// Reflecting a Vector approaching at 45 degrees and off a slanted surface
+fn ut_vector_reflect() {
+    let v = Vector3::new(1.0, -1.0, 0.0);
+    let n = Vector3::new(0.0, 1.0, 0.0);
+    assert_eq!(Vector3::reflect(v, n), Vector3::new(1.0, 1.0, 0.0));
+
+    let v = Vector3::new(0.0, -1.0, 0.0);
+    let n = Vector3::new(2f64.sqrt() / 2.0, 2f64.sqrt() / 2.0, 0.0);
+    assert_eq!(Vector3::reflect(v, n), Vector3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+// Refracting a Vector entering glass and totally reflecting when leaving it
+fn ut_vector_refract() {
+    let n = Vector3::new(0.0, 1.0, 0.0);
+    let v = Vector3::new(0.0, -1.0, 0.0);
+    assert_eq!(Vector3::refract(v, n, 1.0 / 1.5), Some(v));
+
+    let h = 2f64.sqrt() / 2.0;
+    let v = Vector3::new(h, -h, 0.0);
+    let t = Vector3::refract(v, n, 1.0 / 1.5).unwrap();
+    assert!(t.x < v.x && t.y < 0.0);
+    assert_eq!(Vector3::refract(v, n, 1.5), None);
+}
+
+#[test]
+// Interpolating Points and Vectors of float and integer coordinates
+fn ut_vector_and_point_lerp() {
+    let a = Point3::new(0.0, 2.0, -4.0);
+    let b = Point3::new(10.0, 4.0, 4.0);
+    assert_eq!(Point3::lerp(a, b, 0.0), a);
+    assert_eq!(Point3::lerp(a, b, 1.0), b);
+    assert_eq!(Point3::lerp(a, b, 0.25), Point3::new(2.5, 2.5, -2.0));
+    assert_eq!(Point3::lerp(a, b, 0.25).w, 1.0);
+
+    let v = Vector3::lerp(Vector3::new(0, 0, 0), Vector3::new(3, -3, 10), 0.5);
+    assert_eq!(v, Vector3::new(2, -2, 5));
+    assert_eq!(v.w, 0);
+
+    // Extrapolating out of the range of 'i8' falls back to the nearest endpoint
+    let v = Vector3::lerp(Vector3::new(0i8, 0, 0), Vector3::new(100, 10, -100), 2.0);
+    assert_eq!(v, Vector3::new(100, 20, -100));
+}
+
+#[test]
+// Associated consts match the generic constructors
+fn ut_vector_and_point_consts() {
+    assert_eq!(Vector3::<f64>::UNIT_X, Vector3::x_coord(1.0));
+    assert_eq!(Vector3::<f64>::UNIT_Y, Vector3::y_coord(1.0));
+    assert_eq!(Vector3::<f32>::UNIT_Z, Vector3::z_coord(1.0));
+    assert_eq!(Point3::<f64>::ORIGIN, Point3::zero());
+}
+
+#[test]
+// Angles between Vectors and their projection and rejection
+fn ut_vector_angle_project_reject() {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    let x = Vector3::new(2.0, 0.0, 0.0);
+    let xy = Vector3::new(1.0, 1.0, 0.0);
+    assert!((Vector3::angle_between(x, xy) - FRAC_PI_4).abs() < EPSILON);
+    assert!((Vector3::angle_between(x, Vector3::y_coord(1.0)) - FRAC_PI_2).abs() < EPSILON);
+    assert!((Vector3::angle_between(x, -x) - PI).abs() < EPSILON);
+    assert_eq!(Vector3::angle_between(x, Vector3::zero()), 0.0);
+
+    let a = Vector3::new(3.0, 4.0, 5.0);
+    assert_eq!(Vector3::project(a, x), Vector3::new(3.0, 0.0, 0.0));
+    assert_eq!(Vector3::reject(a, x), Vector3::new(0.0, 4.0, 5.0));
+    assert_eq!(Vector3::project(a, x) + Vector3::reject(a, x), a);
+    assert_eq!(Vector3::dot(Vector3::reject(a, xy), xy), 0.0);
+    assert_eq!(Vector3::project(a, Vector3::zero()), Vector3::zero());
+}
+
+#[test]
+// Component-wise min, max, clamp and abs of Points and Vectors
+fn ut_vector_and_point_component_wise() {
+    let a = Point3::new(1.0, -5.0, 3.0);
+    let b = Point3::new(-2.0, 4.0, 3.5);
+    assert_eq!(Point3::min(a, b), Point3::new(-2.0, -5.0, 3.0));
+    assert_eq!(Point3::max(a, b), Point3::new(1.0, 4.0, 3.5));
+    assert_eq!(Point3::max(a, b).w, 1.0);
+
+    let v = Vector3::new(-3, 7, 1);
+    let clamped = Vector3::clamp(v, Vector3::all(0), Vector3::all(5));
+    assert_eq!(clamped, Vector3::new(0, 5, 1));
+    assert_eq!(Vector3::abs(v), Vector3::new(3, 7, 1));
+    assert_eq!(Vector3::abs(v).w, 0);
+}
+
+#[test]
+// Coordinates of Points and Vectors are indexed by axis
+fn ut_vector_and_point_index() {
+    let mut v = Vector3::new(1.0, 2.0, 3.0);
+    let sum: f64 = (0..3).map(|axis| v[axis]).sum();
+    assert_eq!(sum, 6.0);
+    v[1] = 5.0;
+    assert_eq!(v, Vector3::new(1.0, 5.0, 3.0));
+
+    let mut p = Point3::new(1, 2, 3);
+    for axis in 0..3 {
+        p[axis] *= 2;
+    }
+    assert_eq!(p, Point3::new(2, 4, 6));
+    assert_eq!(p[3], 1);
+}
+
+#[test]
+#[should_panic(expected = "Vector3 index out of bounds: 4")]
+// Indexing past the W coordinate panics
+fn ut_vector_index_out_of_bounds() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let _ = v[4];
+}
+
+#[test]
+// Conversions between Points, Vectors, tuples and arrays
+fn ut_vector_and_point_conversions() {
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let v = p.to_vector();
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.to_point(), p);
+    assert_eq!(Point3::zero() + v, p);
+
+    let t: (f64, f64, f64) = p.into();
+    assert_eq!(t, (1.0, 2.0, 3.0));
+    let a: [i32; 3] = Vector3::new(4, 5, 6).into();
+    assert_eq!(a, [4, 5, 6]);
+    assert_eq!(Point3::from(<[f64; 3]>::from(p)), p);
+}
+
+#[test]
+// Vector2 and Point2 operators, equality and display
+fn ut_vector2_and_point2() {
+    let uv = Point2::new(0.25, 0.5);
+    let duv = Vector2::new(0.5, 0.25);
+    println!("{} {}", uv, duv);
+    assert_eq!(uv + duv, Point2::new(0.75, 0.75));
+    assert_eq!(duv + uv, Point2::new(0.75, 0.75));
+    assert_eq!(Point2::new(1.0, 1.0) - uv, Vector2::new(0.75, 0.5));
+    assert_eq!(uv - duv, Point2::new(-0.25, 0.25));
+    assert_eq!(duv - duv, Vector2::default());
+    assert_eq!(-duv * 2.0, Vector2::new(-1.0, -0.5));
+    assert_eq!(uv / 0.5, Point2::new(0.5, 1.0));
+    assert_eq!(Vector2::dot(duv, Vector2::new(2.0, 4.0)), 2.0);
+    assert_eq!(Point2::new(0.1, 0.2), Point2::new(0.1 + EPSILON / 2.0, 0.2));
+}
+
+#[test]
+// Fallible normalization of degenerate Vectors
+fn ut_vector_try_normalized() {
+    let v = Vector3::new(3.0, 0.0, 4.0);
+    assert!(!v.is_normalized());
+    let n = v.try_normalized().unwrap();
+    assert_eq!(n, Vector3::new(0.6, 0.0, 0.8));
+    assert!(n.is_normalized());
+
+    assert_eq!(Vector3::<f64>::zero().try_normalized(), None);
+    assert_eq!(
+        Vector3::new(0f32, -5.0, 0.0).try_normalized(),
+        Some(Vector3::y_coord(-1.0))
+    );
+    assert_eq!(Vector3::new(f64::INFINITY, 0.0, 0.0).try_normalized(), None);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+// Tolerance-based comparisons with the approx crate
+fn ut_vector_and_point_approx() {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_relative_eq!(v, Vector3::new(1.0, 2.0, 3.0 + 1e-12), max_relative = 1e-9);
+    assert_relative_ne!(v, Vector3::new(1.0, 2.0, 3.0 + 1e-6), max_relative = 1e-9);
+    assert_abs_diff_eq!(v.to_point(), Point3::new(1.0, 2.0, 3.001), epsilon = 0.01);
+}
@@ -0,0 +1,57 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![warn(missing_docs, missing_debug_implementations)]
+
+/*!
+# Ruxel
+
+**Ruxel** is a simple ray tracer and renderer written in Rust.
+
+Ruxel allows rendering and ray tracing of:
+- Shapes: Spheres, Planes, Cubes, Cylinders, Triangles, Patterns and OBJ files
+- Attributes: Lights, Shades, Shadows, Patterns, Reflection and Refraction
+
+*/
+
+#![allow(unused_imports)]
+
+/**
+The geometry module implements the functionality for Points, Vectors, Matrices, and their transformations
+*/
+pub mod geometry;
+
+/**
+The lights module implements the functionality for the Light sources that illuminate a scene
+*/
+pub mod lights;
+
+/**
+The picture module implements the functionality for Canvas and Colors in order to create an image file.
+*/
+pub mod picture;
+
+/**
+The prelude module re-exports the most used types and traits for a single glob import
+*/
+pub mod prelude;
+
+/**
+The shapes module implements the functionality for Core and External shapes
+*/
+pub mod shapes;
+
+/**
+The validate module implements debug-mode guards against NaN and infinite values
+*/
+pub mod validate;
+
+/**
+The world module implements the functionality for the collection of Shapes that make up a scene
+*/
+pub mod world;
@@ -0,0 +1,170 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Data structures and methods for Light sources.
+*/
+use num::{Float, Num};
+use std::fmt::Display;
+
+use crate::geometry::{ray::*, scalar::Scalar, vector::*};
+use crate::picture::color_management::srgb_decode;
+use crate::picture::colors::{ColorInit, ColorRgb};
+use std::f64::consts::PI;
+
+// Unit tests for Lights
+#[cfg(test)]
+mod tests;
+
+/// Luminous efficacy in lumens per watt of monochromatic 555nm light, used to
+/// convert photometric units into radiometric ones.
+pub const LUMINOUS_EFFICACY: f64 = 683.0;
+
+/// Power of a Light source in physical units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LightPower {
+    /// Radiant power in watts.
+    Watts(f64),
+    /// Luminous power in lumens.
+    Lumens(f64),
+}
+
+impl LightPower {
+    /// Returns the radiant power in watts.
+    pub fn watts(&self) -> f64 {
+        match *self {
+            LightPower::Watts(w) => w,
+            LightPower::Lumens(lm) => lm / LUMINOUS_EFFICACY,
+        }
+    }
+
+    /// Returns the radiant intensity (watts per steradian) of a point source emitting this
+    /// power evenly in every direction.
+    pub fn isotropic_intensity(&self) -> f64 {
+        self.watts() / (4.0 * PI)
+    }
+}
+
+/// Returns the normalized linear color of a black body at a temperature given in kelvin.
+/// Uses an analytic fit of the Planckian locus that is valid from 1000K to 40000K. The fit
+/// yields sRGB encoded values, which are decoded so the color can be used as radiance.
+pub fn color_from_kelvin(kelvin: f64) -> ColorRgb {
+    let temp = kelvin.clamp(1000.0, 40000.0) / 100.0;
+
+    let r = if temp <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (temp - 60.0).powf(-0.1332047592)
+    };
+    let g = if temp <= 66.0 {
+        99.4708025861 * temp.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (temp - 60.0).powf(-0.0755148492)
+    };
+    let b = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (temp - 10.0).ln() - 305.0447927307
+    };
+
+    let linear = |v: f64| srgb_decode(v.clamp(0.0, 255.0) / 255.0);
+    ColorRgb::new(linear(r), linear(g), linear(b))
+}
+
+/// Trait representing a Light source.
+pub trait Light<P>
+where
+    P: Num + Copy,
+{
+    /// Returns the normalized direction from 'point' towards the Light.
+    fn direction_from(&self, point: Point3<P>) -> Vector3<P>;
+
+    /// Returns the distance from 'point' to the Light,
+    /// or None when the Light is infinitely far away.
+    fn distance_from(&self, point: Point3<P>) -> Option<P>;
+
+    /// Returns the intensity of the Light.
+    fn intensity(&self) -> ColorRgb;
+
+    /// Returns the Ray cast from 'point' towards the Light to test for shadows.
+    fn shadow_ray(&self, point: Point3<P>) -> Ray<P>;
+}
+
+/**
+Type representing a distant Light, like the sun, that illuminates the whole scene
+from the same direction and without falloff. Shadow rays are parallel.
+*/
+#[derive(Clone, Copy, Debug)]
+pub struct DirectionalLight<P> {
+    /// Direction in which the light travels.
+    pub direction: Vector3<P>,
+    /// Intensity of the light.
+    pub intensity: ColorRgb,
+}
+
+impl<P> DirectionalLight<P>
+where
+    P: Scalar + Float,
+{
+    /// Creates a new [`DirectionalLight`] travelling in 'direction'.
+    pub fn new(mut direction: Vector3<P>, intensity: ColorRgb) -> Self {
+        DirectionalLight {
+            direction: direction.normalized(),
+            intensity,
+        }
+    }
+
+    /// Creates a new [`DirectionalLight`] travelling in 'direction' from physical units.
+    /// As the light is infinitely far away, 'power' is taken per square metre of a surface
+    /// facing it: watts per square metre, or lux for lumens. The color of a black body at
+    /// 'kelvin' is normalized to unit luminance, so 'power' alone sets the brightness.
+    pub fn from_physical(direction: Vector3<P>, power: LightPower, kelvin: f64) -> Self {
+        let color = color_from_kelvin(kelvin);
+        let scale = power.watts() / color.luminance();
+        DirectionalLight::new(
+            direction,
+            ColorRgb::new(color.r * scale, color.g * scale, color.b * scale),
+        )
+    }
+}
+
+impl<P> Light<P> for DirectionalLight<P>
+where
+    P: Scalar,
+{
+    fn direction_from(&self, _point: Point3<P>) -> Vector3<P> {
+        -self.direction
+    }
+
+    fn distance_from(&self, _point: Point3<P>) -> Option<P> {
+        None
+    }
+
+    fn intensity(&self) -> ColorRgb {
+        self.intensity
+    }
+
+    fn shadow_ray(&self, point: Point3<P>) -> Ray<P> {
+        Ray::new(point, self.direction_from(point))
+    }
+}
+
+impl<P> Display for DirectionalLight<P>
+where
+    P: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "DirectionalLight - dir: {}\tintensity: {}",
+            self.direction, self.intensity
+        );
+        f.write_str(&s)
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for Light types.
+
+use super::*;
+use crate::picture::colors::ColorInit;
+
+#[test]
+// A directional light has a normalized direction and no falloff
+fn ut_lights_directional_creation() {
+    let light = DirectionalLight::new(Vector3::new(0.0, -2.0, 0.0), ColorRgb::WHITE);
+    assert_eq!(light.direction, Vector3::y_coord(-1.0));
+    assert_eq!(light.intensity(), ColorRgb::WHITE);
+    assert_eq!(light.distance_from(Point3::new(1.0, 2.0, 3.0)), None);
+    println!("{}", light);
+}
+
+#[test]
+// Shadow rays of a directional light are parallel
+fn ut_lights_directional_parallel_shadow_rays() {
+    let light = DirectionalLight::new(Vector3::new(1.0, -1.0, 0.0), ColorRgb::WHITE);
+    let r1 = light.shadow_ray(Point3::zero());
+    let r2 = light.shadow_ray(Point3::new(10.0, 0.0, -4.0));
+    assert_eq!(r1.direction, r2.direction);
+    assert_eq!(r2.origin, Point3::new(10.0, 0.0, -4.0));
+    assert_eq!(
+        light.direction_from(Point3::zero()),
+        Vector3::new(-1.0, 1.0, 0.0) / 2f64.sqrt()
+    );
+}
+
+#[test]
+// Conversion between photometric and radiometric units
+fn ut_lights_power_units() {
+    assert_eq!(LightPower::Watts(100.0).watts(), 100.0);
+    assert_eq!(LightPower::Lumens(683.0).watts(), 1.0);
+    assert!((LightPower::Watts(4.0 * PI).isotropic_intensity() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+// Color temperature conversion
+fn ut_lights_color_from_kelvin() {
+    assert_eq!(color_from_kelvin(6600.0), ColorRgb::WHITE);
+    let candle = color_from_kelvin(1000.0);
+    assert_eq!(candle.r, 1.0);
+    assert_eq!(candle.b, 0.0);
+    // Linear values: the encoded fit gives 0.25 for the green of a candle.
+    assert!(candle.g > 0.04 && candle.g < 0.06);
+    let sky = color_from_kelvin(15000.0);
+    assert!(sky.b > sky.r);
+}
+
+#[test]
+// Directional lights built from physical power and color temperature
+fn ut_lights_directional_from_physical() {
+    let light: DirectionalLight<f64> =
+        DirectionalLight::from_physical(Vector3::y_coord(-1.0), LightPower::Watts(2.0), 6600.0);
+    assert_eq!(light.intensity(), ColorRgb::new(2.0, 2.0, 2.0));
+
+    let warm: DirectionalLight<f64> =
+        DirectionalLight::from_physical(Vector3::y_coord(-1.0), LightPower::Lumens(683.0), 3000.0);
+    assert!((warm.intensity().luminance() - 1.0).abs() < 1e-12);
+    assert!(warm.intensity().r > warm.intensity().b);
+}
@@ -6,12 +6,33 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/**
+/*!
 The Picture module implements the functionality for Canvas, Colors and Pixel types
 */
 
 /// Provides the data structure and implementation of the Color type
 pub mod colors;
 
+/// Provides the output transforms of the minimal color management
+pub mod color_management;
+
 /// Provides the data structure and implementation of the Canvas type
 pub mod canvas;
+
+/// Provides the comparison of two Canvas: difference images and perceptual scores
+pub mod diff;
+
+/// Provides the data structure and implementation of image Textures
+pub mod texture;
+
+/// Provides color quantization and the indexed image type
+pub mod palette;
+
+/// Provides the data structure and implementation of frame Animations
+pub mod animation;
+
+/// Provides the vector SvgCanvas used by debug renders
+pub mod svg;
+
+/// Provides the orders in which image tiles are rendered
+pub mod tiles;
@@ -0,0 +1,110 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Data structure and operations for Animations: sequences of Canvas written as animated GIF.
+*/
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::picture::canvas::Canvas;
+use crate::picture::palette::{header_dimensions, ImageError, MAX_PALETTE_COLORS};
+
+// Animation Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Defines a sequence of frames displayed with a fixed delay, e.g. a turntable.
+#[derive(Debug, Clone, Default)]
+pub struct Animation {
+    // Frames of the Animation, all with the same dimensions.
+    frames: Vec<Canvas>,
+    /// Time each frame is displayed.
+    pub frame_delay: Duration,
+    /// Whether the Animation restarts after the last frame.
+    pub looping: bool,
+}
+
+impl Animation {
+    /// Creates a new looping [`Animation`] without frames.
+    pub fn new(frame_delay: Duration) -> Animation {
+        Animation {
+            frames: vec![],
+            frame_delay,
+            looping: true,
+        }
+    }
+
+    /// Returns the frames of the Animation, all with the same dimensions.
+    pub fn frames(&self) -> &[Canvas] {
+        &self.frames
+    }
+
+    /// Appends a frame to the Animation.
+    /// Returns an error, without appending it, when the frame does not have the dimensions
+    /// of the first frame.
+    pub fn push_frame(&mut self, frame: Canvas) -> Result<(), ImageError> {
+        if let Some(first) = self.frames.first() {
+            if first.width != frame.width || first.height != frame.height {
+                return Err(ImageError::FrameSize {
+                    width: frame.width,
+                    height: frame.height,
+                });
+            }
+        }
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    /// Writes the Animation to an animated GIF file. Every frame is quantized
+    /// to its own palette of up to 256 colors.
+    /// GIF stores delays in hundredths of a second, so the delay is rounded accordingly.
+    /// Returns an error, without creating the file, for Animations without frames, empty
+    /// frames and frames larger than 65535 pixels per side.
+    pub fn write_to_gif(&self, file_name: &Path) -> Result<(), ImageError> {
+        let (width, height) = match self.frames.first() {
+            Some(first) => header_dimensions::<u16>(first.width, first.height)?,
+            None => return Err(ImageError::Empty),
+        };
+        let image = File::create(file_name)?;
+        let mut encoder = gif::Encoder::new(BufWriter::new(image), width, height, &[])?;
+        if self.looping {
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+        }
+
+        let delay = ((self.frame_delay.as_millis() + 5) / 10).min(u16::MAX as u128) as u16;
+        for canvas in &self.frames {
+            let indexed = canvas.quantize(MAX_PALETTE_COLORS);
+            let frame = gif::Frame {
+                width,
+                height,
+                delay,
+                palette: Some(indexed.palette_bytes()),
+                buffer: Cow::Borrowed(&indexed.indices),
+                ..Default::default()
+            };
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Animation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "Animation - frames: {} delay: {:?}\n",
+            self.frames.len(),
+            self.frame_delay
+        );
+        f.write_str(&s)
+    }
+}
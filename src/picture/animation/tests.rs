@@ -0,0 +1,71 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit testing for the Animation types
+use super::*;
+use crate::picture::canvas::Pixel;
+use crate::picture::colors::{ColorInit, ColorRgb};
+
+#[test]
+// A turntable of frames is written as an animated GIF
+fn ut_animation_write_gif() {
+    let mut animation = Animation::new(Duration::from_millis(40));
+    for i in 0..4 {
+        let mut frame = Canvas::new(4, 4);
+        frame.write_pixel(Pixel::new(i, i, ColorRgb::red()));
+        animation.push_frame(frame).unwrap();
+    }
+    assert_eq!(animation.frames().len(), 4);
+    println!("{}", animation);
+    let path = std::env::temp_dir().join("ruxel_ut_animation.gif");
+    animation.write_to_gif(&path).unwrap();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+    let mut frames = 0;
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 4);
+        // The red pixel moves along the diagonal, starting at the bottom-left corner.
+        let offset = ((3 - frames) * 4 + frames) * 4;
+        assert_eq!(&frame.buffer[offset..offset + 3], &[255, 0, 0]);
+        frames += 1;
+    }
+    assert_eq!(frames, 4);
+}
+
+#[test]
+// Frames of different sizes are rejected
+fn ut_animation_frame_dimensions() {
+    let mut animation = Animation::new(Duration::from_millis(100));
+    assert_eq!(animation.push_frame(Canvas::new(4, 4)), Ok(()));
+    assert_eq!(
+        animation.push_frame(Canvas::new(2, 4)),
+        Err(ImageError::FrameSize {
+            width: 2,
+            height: 4
+        })
+    );
+    assert_eq!(animation.frames().len(), 1);
+}
+
+#[test]
+// Animations the GIF format cannot store are rejected
+fn ut_animation_write_gif_errors() {
+    let path = std::env::temp_dir().join("ruxel_ut_animation_error.gif");
+    let mut animation = Animation::new(Duration::from_millis(100));
+    assert_eq!(animation.write_to_gif(&path), Err(ImageError::Empty));
+    animation.push_frame(Canvas::new(70_000, 1)).unwrap();
+    assert_eq!(
+        animation.write_to_gif(&path),
+        Err(ImageError::TooLarge {
+            width: 70_000,
+            height: 1
+        })
+    );
+}
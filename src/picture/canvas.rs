@@ -10,99 +10,292 @@
 Data structure and operations for the Canvas and Pixel types.
 */
 use std::fmt::Display;
-use std::fs::OpenOptions;
-use std::io::Write;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
+use crate::picture::color_management::OutputTransform;
 use crate::picture::colors::*;
+use crate::picture::palette::{header_dimensions, median_cut, ImageError, IndexedImage};
+use crate::validate::{validate_color, validate_value, Origin};
+
+/// Middle-grey key value targeted by [`Canvas::auto_exposure`] by default.
+pub const DEFAULT_EXPOSURE_KEY: f64 = 0.18;
 
 // Canvas Unit Tests
 #[cfg(test)]
 mod tests;
 
+/// Trait for the values a Canvas can hold: colors for beauty passes, grayscale
+/// values for depth maps and masks, or 8-bit colors.
+pub trait PixelFormat: Copy + Default {
+    /// Returns the pixel as a color, used by the image writers.
+    fn to_rgb(&self) -> ColorRgb;
+
+    /// Returns the pixel checked by the validate module before it is written to a Canvas.
+    fn validated(self, _origin: Origin) -> Self {
+        self
+    }
+}
+
+impl PixelFormat for ColorRgb {
+    fn to_rgb(&self) -> ColorRgb {
+        *self
+    }
+
+    fn validated(self, origin: Origin) -> Self {
+        validate_color(self, origin)
+    }
+}
+
+impl PixelFormat for ColorRgba {
+    // The alpha component is ignored: the color is written as if it were opaque.
+    fn to_rgb(&self) -> ColorRgb {
+        ColorRgb::new(self.r, self.g, self.b)
+    }
+
+    fn validated(self, origin: Origin) -> Self {
+        let c = validate_color(self.to_rgb(), origin);
+        ColorRgba {
+            a: self.a,
+            ..c.into()
+        }
+    }
+}
+
+impl PixelFormat for ColorKey {
+    fn to_rgb(&self) -> ColorRgb {
+        ColorRgb::from(*self)
+    }
+}
+
+impl PixelFormat for f32 {
+    // Grayscale value, written with the same intensity on every channel.
+    fn to_rgb(&self) -> ColorRgb {
+        ColorRgb::new(*self as f64, *self as f64, *self as f64)
+    }
+
+    fn validated(self, origin: Origin) -> Self {
+        validate_value(self, origin)
+    }
+}
+
 /// Defines the structure of a pixel in a Canvas, with X and Y coordinates filled with Color.
-#[derive(Debug, Copy, Clone)]
-pub struct Pixel {
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Pixel<T = ColorRgb> {
     /// X coordinate.
     pub(crate) x: usize,
     /// Y coordinate.
     pub(crate) y: usize,
     /// Color for the pixel.  
-    pub(crate) color: ColorRgb,
+    pub(crate) color: T,
+}
+
+/// Corner of the Canvas where pixel coordinates start, with X growing to the right and
+/// Y growing away from the origin.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum CanvasOrigin {
+    /// Y grows upwards from the bottom-left corner, as in a Cartesian plot.
+    #[default]
+    BottomLeft,
+    /// Y grows downwards from the top-left corner, as in most image formats.
+    TopLeft,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
 /// Defines the structure of a Canvas to be filled with pixels and written to an image format.
-pub struct Canvas {
+/// The pixels are colors by default, and any other [`PixelFormat`] can be used instead.
+pub struct Canvas<T = ColorRgb> {
     /// Width of the Canvas.
     pub width: usize,
     /// Height of the Canvas.
     pub height: usize,
     /// Dynamic 2D matrix of data holding the pixel data in the Canvas.
     /// This data is written to the output image format.
-    pub data: Vec<Vec<ColorRgb>>,
+    /// It is indexed as data[x][row], with row 0 at the top regardless of the origin.
+    pub data: Vec<Vec<T>>,
+    /// Corner used by the pixel coordinates in write_pixel and read_pixel.
+    pub origin: CanvasOrigin,
 }
 
-impl Pixel {
+/// Summary statistics of the pixels in a Canvas.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct CanvasStatistics {
+    /// Smallest pixel luminance.
+    pub min_luminance: f64,
+    /// Largest pixel luminance.
+    pub max_luminance: f64,
+    /// Arithmetic mean of the pixel luminance.
+    pub mean_luminance: f64,
+    /// Log-average (geometric mean) of the pixel luminance.
+    pub log_average_luminance: f64,
+    /// Percentage of pixels with any component outside the displayable [0, 1] range.
+    pub clipped_percentage: f64,
+}
+
+impl<T: PixelFormat> Pixel<T> {
     /// Returns the color of this [`Pixel`].
-    pub fn color(&self) -> ColorRgb {
+    pub fn color(&self) -> T {
         self.color
     }
 
     /// Sets the color of this [`Pixel`].
-    pub fn set_color(&mut self, c: ColorRgb) {
+    pub fn set_color(&mut self, c: T) {
         self.color = c;
     }
 
     /// Creates a new [`Pixel`].
-    pub fn new(x: usize, y: usize, color: ColorRgb) -> Pixel {
+    pub fn new(x: usize, y: usize, color: T) -> Pixel<T> {
         Pixel { x, y, color }
     }
 }
 
-impl Default for Pixel {
-    fn default() -> Self {
-        Self {
-            x: Default::default(),
-            y: Default::default(),
-            color: Default::default(),
-        }
-    }
-}
-
-impl Display for Pixel {
+impl<T: Display> Display for Pixel<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = format!("pix - [x:{}, y:{}] c:{}", self.x, self.y, self.color);
         f.write_str(&s)
     }
 }
 
-impl Canvas {
-    /// Creates a new Canvas of specified Width and Height filled with black pixels.
-    pub fn new(width: usize, height: usize) -> Canvas {
+impl<T: PixelFormat> Canvas<T> {
+    /// Creates a new Canvas of specified Width and Height filled with 'value'.
+    pub fn filled(width: usize, height: usize, value: T) -> Canvas<T> {
         Canvas {
             width,
             height,
-            data: vec![vec![ColorRgb::default(); height]; width],
+            data: vec![vec![value; height]; width],
+            origin: CanvasOrigin::BottomLeft,
+        }
+    }
+
+    // Returns the row of Canvas.data[[]] holding the pixels with coordinate 'y'.
+    fn row(&self, y: usize) -> usize {
+        match self.origin {
+            // The '-1' is to not get an out of bounds error on the vector for the
+            // first iteration.
+            CanvasOrigin::BottomLeft => self.height - 1 - y,
+            CanvasOrigin::TopLeft => y,
         }
     }
 
     /// Fills the Canvas.data[[]] vector with pixels  
-    pub fn write_pixel(&mut self, pixel: Pixel) {
+    pub fn write_pixel(&mut self, pixel: Pixel<T>) {
         // Filling the canvas with the corresponding pixel color
-        // In order to transform to Canvas coordinates we need to
-        // substract the canvas height from the pixel.y position
-        // The '-1' is to not get an out of bounds error on the vector for the
-        // first iteration.
-        self.data[pixel.x][self.height - 1 - pixel.y] = pixel.color;
+        // In order to transform to Canvas coordinates the pixel.y position
+        // is converted according to the Canvas origin.
+        let row = self.row(pixel.y);
+        self.data[pixel.x][row] = pixel.color.validated(Origin::pixel(pixel.x, pixel.y));
+    }
+
+    /// Returns the color of the pixel at 'x' and 'y', using the same origin as write_pixel.
+    pub fn read_pixel(&self, x: usize, y: usize) -> T {
+        self.data[x][self.row(y)]
+    }
+
+    /// Returns an iterator over the pixels of the Canvas, with the same coordinates as
+    /// write_pixel.
+    pub fn pixels(&self) -> impl Iterator<Item = Pixel<T>> + '_ {
+        (0..self.width).flat_map(move |x| {
+            (0..self.height).map(move |y| Pixel::new(x, y, self.read_pixel(x, y)))
+        })
+    }
+
+    /// Returns a new Canvas with the same dimensions and origin, holding 'f' applied to
+    /// every pixel.
+    pub fn map<U: PixelFormat>(&self, f: impl Fn(T) -> U) -> Canvas<U> {
+        Canvas {
+            width: self.width,
+            height: self.height,
+            data: self
+                .data
+                .iter()
+                .map(|column| column.iter().map(|v| f(*v)).collect())
+                .collect(),
+            origin: self.origin,
+        }
+    }
+
+    /// Returns the Canvas converted to colors, e.g. to quantize it or compare it.
+    pub fn to_rgb(&self) -> Canvas {
+        self.map(|v| v.to_rgb())
+    }
+
+    /// Returns the Canvas converted to colors encoded with an output transform, e.g. sRGB or
+    /// Display P3, to pass to an exporter. The Canvas itself keeps its linear values, so
+    /// exposure, statistics and auto_exposure are not affected.
+    pub fn encoded(&self, transform: OutputTransform) -> Canvas {
+        self.map(|v| transform.apply(v.to_rgb()))
+    }
+
+    // Returns the average color of the pixels in [x0, x1) x [y0, y1) of Canvas.data[[]].
+    fn average(&self, x0: usize, x1: usize, y0: usize, y1: usize) -> ColorRgb {
+        let mut sum = ColorRgb::BLACK;
+        let mut count = 0;
+        for column in &self.data[x0..x1] {
+            for color in &column[y0..y1] {
+                sum += color.to_rgb();
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return sum;
+        }
+        ColorRgb::new(
+            sum.r / count as f64,
+            sum.g / count as f64,
+            sum.b / count as f64,
+        )
+    }
+
+    /// Returns the Canvas downsampled to 'cols' columns as a string of truecolor ANSI
+    /// escape sequences, using the upper half block character to fit two rows of pixels
+    /// per line of text, so it can be displayed on a terminal.
+    pub fn to_ansi_string(&self, cols: usize) -> String {
+        if cols == 0 || self.width == 0 || self.height == 0 {
+            return String::new();
+        }
+        let cols = cols.min(self.width);
+        let scale = self.width as f64 / cols as f64;
+        let rows = ((self.height as f64 / scale).round() as usize).max(1);
+
+        let bounds = |i: usize, n: usize, len: usize| {
+            let lo = ((i * len) as f64 / n as f64) as usize;
+            let hi = (((i + 1) * len) as f64 / n as f64) as usize;
+            (lo.min(len - 1), hi.max(lo + 1).min(len))
+        };
+
+        let mut s = String::new();
+        for line in 0..(rows + 1) / 2 {
+            for col in 0..cols {
+                let (x0, x1) = bounds(col, cols, self.width);
+                let (y0, y1) = bounds(2 * line, rows, self.height);
+                let top = ColorKey::from(self.average(x0, x1, y0, y1));
+                s.push_str(&format!("\x1b[38;2;{};{};{}m", top.r, top.g, top.b));
+                if 2 * line + 1 < rows {
+                    let (y0, y1) = bounds(2 * line + 1, rows, self.height);
+                    let bottom = ColorKey::from(self.average(x0, x1, y0, y1));
+                    s.push_str(&format!(
+                        "\x1b[48;2;{};{};{}m",
+                        bottom.r, bottom.g, bottom.b
+                    ));
+                } else {
+                    s.push_str("\x1b[49m");
+                }
+                s.push('\u{2580}');
+            }
+            s.push_str("\x1b[0m\n");
+        }
+        s
     }
 
     /// Iterates over the Canvas.data[[]] vector and generates a
     /// PPM file with the proper format
+    #[cfg_attr(feature = "profiling", profiling::function)]
     pub fn write_to_ppm(&self, file_name: &Path) {
         let mut image = OpenOptions::new()
             .write(true)
             .create(true)
+            .truncate(true)
             .open(file_name)
             .expect("Cannot open image file");
 
@@ -122,12 +315,13 @@ impl Canvas {
                     image_file_content.push('\n');
                     colors_per_ppm_line = 0;
                 }
+                let color = self.data[j][i].to_rgb();
                 image_file_content.push_str(
                     format!(
                         "{} {} {} ",
-                        ((self.data[j][i].r * 255f64).ceil() as u8).clamp(0, 255),
-                        ((self.data[j][i].g * 255f64).ceil() as u8).clamp(0, 255),
-                        ((self.data[j][i].b * 255f64).ceil() as u8).clamp(0, 255)
+                        ((color.r * 255f64).ceil() as u8).clamp(0, 255),
+                        ((color.g * 255f64).ceil() as u8).clamp(0, 255),
+                        ((color.b * 255f64).ceil() as u8).clamp(0, 255)
                     )
                     .as_str(),
                 );
@@ -139,19 +333,196 @@ impl Canvas {
             .write_all(image_file_content.as_bytes())
             .expect("Write failed");
     }
+
+    // Returns the interleaved RGB samples of the Canvas, row by row from the top, encoded with
+    // 'transform', clamped to [0, 1] and scaled to the full 16-bit range.
+    fn rgb16_samples(&self, transform: OutputTransform) -> Vec<u16> {
+        let quantize = |v: f64| (v.clamp(0.0, 1.0) * 65535.0).round() as u16;
+        let mut samples = Vec::with_capacity(self.width * self.height * 3);
+        for row in 0..self.height {
+            for column in &self.data {
+                let color = transform.apply(column[row].to_rgb());
+                samples.extend([quantize(color.r), quantize(color.g), quantize(color.b)]);
+            }
+        }
+        samples
+    }
+
+    /// Writes the Canvas to an RGB PNG file with 16 bits per channel, avoiding the banding of
+    /// 8-bit output in smooth gradients. The colors are encoded with 'transform', and the
+    /// file is tagged with its gamma and primaries so viewers decode them correctly.
+    /// Returns an error, without creating the file, for empty images.
+    pub fn write_to_png16(
+        &self,
+        file_name: &Path,
+        transform: OutputTransform,
+    ) -> Result<(), ImageError> {
+        let (width, height) = header_dimensions::<u32>(self.width, self.height)?;
+        let image = File::create(file_name)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(image), width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Sixteen);
+        set_png_color_space(&mut encoder, transform);
+        let mut writer = encoder.write_header()?;
+        // PNG stores 16-bit samples in big-endian byte order.
+        let bytes: Vec<u8> = self
+            .rgb16_samples(transform)
+            .iter()
+            .flat_map(|sample| sample.to_be_bytes())
+            .collect();
+        writer.write_image_data(&bytes)?;
+        Ok(())
+    }
+
+    /// Writes the Canvas to an RGB TIFF file with 16 bits per channel, with the colors
+    /// encoded with 'transform'. TIFF readers assume no particular transfer function, so the
+    /// file is not tagged: pick the transform expected by the grading tool.
+    /// Returns an error, without creating the file, for empty images.
+    #[cfg(feature = "tiff")]
+    pub fn write_to_tiff16(
+        &self,
+        file_name: &Path,
+        transform: OutputTransform,
+    ) -> Result<(), ImageError> {
+        let (width, height) = header_dimensions::<u32>(self.width, self.height)?;
+        let image = File::create(file_name)?;
+        let mut encoder = tiff::encoder::TiffEncoder::new(BufWriter::new(image))?;
+        encoder.write_image::<tiff::encoder::colortype::RGB16>(
+            width,
+            height,
+            &self.rgb16_samples(transform),
+        )?;
+        Ok(())
+    }
 }
 
-impl Default for Canvas {
-    fn default() -> Self {
-        Self {
-            width: Default::default(),
-            height: Default::default(),
-            data: Default::default(),
+// Tags a PNG with the gAMA and cHRM chunks of an output transform. The gAMA chunk stores a
+// single encoding exponent, times 100000: 1/2.2 for the sRGB curve, as the PNG specification
+// recommends, and the usual 1/1.961 approximation for the Rec. 709 curve.
+fn set_png_color_space<W: Write>(encoder: &mut png::Encoder<W>, transform: OutputTransform) {
+    let gamma = match transform {
+        OutputTransform::Linear => 100_000,
+        OutputTransform::Srgb | OutputTransform::DisplayP3 => 45_455,
+        OutputTransform::Rec709 => 50_994,
+    };
+    let (red, green, blue) = match transform {
+        OutputTransform::DisplayP3 => ((0.680, 0.320), (0.265, 0.690), (0.150, 0.060)),
+        _ => ((0.640, 0.330), (0.300, 0.600), (0.150, 0.060)),
+    };
+    encoder.set_source_gamma(png::ScaledFloat::from_scaled(gamma));
+    encoder.set_source_chromaticities(png::SourceChromaticities::new(
+        (0.3127, 0.3290),
+        red,
+        green,
+        blue,
+    ));
+}
+
+impl Canvas {
+    /// Creates a new Canvas of specified Width and Height filled with black pixels.
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas::filled(width, height, ColorRgb::default())
+    }
+
+    /// Creates a new Canvas like [`Canvas::new`] with pixel coordinates starting at 'origin'.
+    pub fn with_origin(width: usize, height: usize, origin: CanvasOrigin) -> Canvas {
+        Canvas {
+            origin,
+            ..Canvas::new(width, height)
         }
     }
+
+    /// Scales every pixel of the Canvas by 2^stops.
+    pub fn exposure(&mut self, stops: f64) {
+        let scale = 2f64.powf(stops);
+        for color in self.data.iter_mut().flatten() {
+            color.r *= scale;
+            color.g *= scale;
+            color.b *= scale;
+        }
+    }
+
+    /// Returns the log-average (geometric mean) luminance of the Canvas.
+    pub fn log_average_luminance(&self) -> f64 {
+        // Small delta to avoid the singularity of black pixels.
+        let delta = 1e-4;
+        let count = self.width * self.height;
+        if count == 0 {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .data
+            .iter()
+            .flatten()
+            .map(|c| (delta + c.luminance().max(0.0)).ln())
+            .sum();
+        (sum / count as f64).exp()
+    }
+
+    /// Scales the Canvas so its log-average luminance matches 'key'
+    /// (e.g. [`DEFAULT_EXPOSURE_KEY`]) and returns the exposure in stops that was applied.
+    pub fn auto_exposure(&mut self, key: f64) -> f64 {
+        let average = self.log_average_luminance();
+        if average <= 0.0 {
+            return 0.0;
+        }
+        let stops = (key / average).log2();
+        self.exposure(stops);
+        stops
+    }
+
+    /// Returns a luminance histogram of the Canvas with 'bins' buckets over the [0, 1] range.
+    /// Values below 0 are counted in the first bucket and values above 1 in the last one.
+    pub fn histogram(&self, bins: usize) -> Vec<usize> {
+        let mut histogram = vec![0; bins];
+        if bins == 0 {
+            return histogram;
+        }
+        for color in self.data.iter().flatten() {
+            let bin = (color.luminance().clamp(0.0, 1.0) * bins as f64) as usize;
+            histogram[bin.min(bins - 1)] += 1;
+        }
+        histogram
+    }
+
+    /// Returns the luminance [`CanvasStatistics`] of the Canvas.
+    pub fn statistics(&self) -> CanvasStatistics {
+        let count = self.width * self.height;
+        if count == 0 {
+            return CanvasStatistics::default();
+        }
+        let mut stats = CanvasStatistics {
+            min_luminance: f64::INFINITY,
+            max_luminance: f64::NEG_INFINITY,
+            ..Default::default()
+        };
+        let mut clipped = 0;
+        for color in self.data.iter().flatten() {
+            let luminance = color.luminance();
+            stats.min_luminance = stats.min_luminance.min(luminance);
+            stats.max_luminance = stats.max_luminance.max(luminance);
+            stats.mean_luminance += luminance;
+            if [color.r, color.g, color.b]
+                .iter()
+                .any(|c| !(0.0..=1.0).contains(c))
+            {
+                clipped += 1;
+            }
+        }
+        stats.mean_luminance /= count as f64;
+        stats.log_average_luminance = self.log_average_luminance();
+        stats.clipped_percentage = 100.0 * clipped as f64 / count as f64;
+        stats
+    }
+
+    /// Reduces the Canvas to at most 'n_colors' colors (up to 256) with the median cut
+    /// algorithm and returns the resulting [`IndexedImage`].
+    pub fn quantize(&self, n_colors: usize) -> IndexedImage {
+        median_cut(self, n_colors)
+    }
 }
 
-impl Display for Canvas {
+impl<T> Display for Canvas<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = format!(
             "Canvas - \n width : height [{} : {}] \n",
@@ -21,7 +21,7 @@ fn ut_canvas_print_to_ppm() {
     canvas.write_pixel(Pixel::new(0, 0, c1));
     canvas.write_pixel(Pixel::new(2, 1, c2));
     canvas.write_pixel(Pixel::new(4, 2, c3));
-    canvas.write_to_ppm(&image_path);
+    canvas.write_to_ppm(image_path);
 }
 #[test]
 // This test validates the printing of a rocket trayectory using Vector and colors in a PPM Canvas
@@ -48,13 +48,13 @@ fn ut_canvas_projectile_launch_canvas() {
     };
 
     let env = Environment {
-        gravity: Vector3::down() / 10f64,
-        wind: Vector3::left() / 100f64,
+        gravity: Vector3::y_coord(-1.0) / 10f64,
+        wind: Vector3::x_coord(-1.0) / 100f64,
     };
 
     let mut canvas = Canvas::new(900, 550);
 
-    fn tick<'a, 'b>(env: &'a Environment, proj: &'b mut Projectile) -> &'b mut Projectile {
+    fn tick<'b>(env: &Environment, proj: &'b mut Projectile) -> &'b mut Projectile {
         proj.position = proj.position + proj.velocity;
         proj.velocity = proj.velocity + env.gravity + env.wind;
         proj
@@ -94,3 +94,195 @@ fn ut_canvas_projectile_launch_canvas() {
 
     canvas.write_to_ppm(image_path);
 }
+
+#[test]
+// Manual and automatic exposure of an HDR canvas
+fn ut_canvas_exposure() {
+    let mut canvas = Canvas::new(4, 2);
+    for x in 0..4 {
+        canvas.write_pixel(Pixel::new(x, 0, ColorRgb::new(2.0, 2.0, 2.0)));
+        canvas.write_pixel(Pixel::new(x, 1, ColorRgb::new(8.0, 8.0, 8.0)));
+    }
+    assert!((canvas.log_average_luminance() - 4.0).abs() < 1e-3);
+
+    let stops = canvas.auto_exposure(DEFAULT_EXPOSURE_KEY);
+    assert!(stops < 0.0);
+    assert!((canvas.log_average_luminance() - DEFAULT_EXPOSURE_KEY).abs() < 1e-3);
+
+    canvas.exposure(-stops);
+    assert_eq!(canvas.data[0][0], ColorRgb::new(8.0, 8.0, 8.0));
+}
+
+#[test]
+// Luminance histogram and statistics of a canvas
+fn ut_canvas_histogram_and_statistics() {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.write_pixel(Pixel::new(0, 0, ColorRgb::WHITE));
+    canvas.write_pixel(Pixel::new(1, 0, ColorRgb::new(0.5, 0.5, 0.5)));
+    canvas.write_pixel(Pixel::new(0, 1, ColorRgb::new(3.0, 3.0, 3.0)));
+
+    assert_eq!(canvas.histogram(4), vec![1, 0, 1, 2]);
+    assert_eq!(canvas.histogram(0), Vec::<usize>::new());
+
+    let stats = canvas.statistics();
+    assert_eq!(stats.min_luminance, 0.0);
+    assert!((stats.max_luminance - 3.0).abs() < 1e-9);
+    assert!((stats.mean_luminance - 1.125).abs() < 1e-9);
+    assert_eq!(stats.clipped_percentage, 25.0);
+    assert_eq!(Canvas::new(0, 0).statistics(), CanvasStatistics::default());
+}
+
+#[test]
+// Terminal preview of a canvas with truecolor half blocks
+fn ut_canvas_to_ansi_string() {
+    let mut canvas = Canvas::new(4, 4);
+    for x in 0..4 {
+        canvas.write_pixel(Pixel::new(x, 3, ColorRgb::red()));
+        canvas.write_pixel(Pixel::new(x, 2, ColorRgb::red()));
+        canvas.write_pixel(Pixel::new(x, 0, ColorRgb::blue()));
+        canvas.write_pixel(Pixel::new(x, 1, ColorRgb::blue()));
+    }
+    // Downsampled to 2x2 pixels: a single line of two red-over-blue cells.
+    let s = canvas.to_ansi_string(2);
+    let cell = "\x1b[38;2;255;0;0m\x1b[48;2;0;0;255m\u{2580}";
+    assert_eq!(s, format!("{}{}\x1b[0m\n", cell, cell));
+    print!("{}", s);
+
+    assert_eq!(canvas.to_ansi_string(4).lines().count(), 2);
+    assert_eq!(
+        Canvas::new(3, 1)
+            .to_ansi_string(3)
+            .matches("\x1b[49m")
+            .count(),
+        3
+    );
+    assert_eq!(canvas.to_ansi_string(0), "");
+}
+
+#[test]
+// Pixel coordinates follow the origin of the canvas for reads and writes
+fn ut_canvas_origin() {
+    let mut bottom = Canvas::new(2, 3);
+    let mut top = Canvas::with_origin(2, 3, CanvasOrigin::TopLeft);
+    bottom.write_pixel(Pixel::new(1, 0, ColorRgb::red()));
+    top.write_pixel(Pixel::new(1, 0, ColorRgb::red()));
+
+    assert_eq!(bottom.read_pixel(1, 0), ColorRgb::red());
+    assert_eq!(top.read_pixel(1, 0), ColorRgb::red());
+    // Storage (and every export) is top-down, so the pixels land on opposite rows.
+    assert_eq!(bottom.data[1][2], ColorRgb::red());
+    assert_eq!(top.data[1][0], ColorRgb::red());
+    assert_eq!(
+        Canvas::<ColorRgb>::default().origin,
+        CanvasOrigin::BottomLeft
+    );
+}
+
+#[test]
+// Canvas holding other pixel formats share the writers of color Canvas
+fn ut_canvas_pixel_formats() {
+    let mut depth: Canvas<f32> = Canvas::filled(2, 2, 0.0);
+    depth.write_pixel(Pixel::new(0, 0, 0.5));
+    assert_eq!(depth.read_pixel(0, 0), 0.5);
+    assert_eq!(depth.pixels().filter(|p| p.color() > 0.0).count(), 1);
+    assert_eq!(
+        depth.to_rgb().read_pixel(0, 0),
+        ColorRgb::new(0.5, 0.5, 0.5)
+    );
+
+    let mask = depth.map(|d| ColorKey::from(ColorRgb::new(d as f64, 0.0, 0.0)));
+    assert_eq!(mask.read_pixel(0, 0), ColorKey { r: 128, g: 0, b: 0 });
+
+    let mut beauty: Canvas<ColorRgba> = Canvas::filled(1, 1, ColorRgba::default());
+    beauty.write_pixel(Pixel::new(0, 0, ColorRgb::red().into()));
+    assert_eq!(beauty.to_rgb().data, vec![vec![ColorRgb::red()]]);
+
+    let path = std::env::temp_dir().join("ruxel_ut_canvas_depth.ppm");
+    depth.write_to_ppm(&path);
+    let ppm = std::fs::read_to_string(&path).unwrap();
+    assert!(ppm.starts_with("P3\n2 2\n255\n0 0 0 \n0 0 0 \n128 128 128 "));
+}
+
+#[test]
+// Canvas written to 16-bit files keep the precision lost by 8-bit formats
+fn ut_canvas_16_bit_export() {
+    let mut canvas = Canvas::with_origin(2, 2, CanvasOrigin::TopLeft);
+    canvas.write_pixel(Pixel::new(0, 0, ColorRgb::new(0.5, 1.5, -0.5)));
+    canvas.write_pixel(Pixel::new(1, 1, ColorRgb::new(0.0, 0.0, 1.0 / 1024.0)));
+    let expected: Vec<u16> = vec![32768, 65535, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64];
+    assert_eq!(canvas.rgb16_samples(OutputTransform::Linear), expected);
+
+    let png_path = std::env::temp_dir().join("ruxel_ut_canvas_16.png");
+    canvas
+        .write_to_png16(&png_path, OutputTransform::Linear)
+        .unwrap();
+    let decoder = png::Decoder::new(File::open(&png_path).unwrap());
+    let mut reader = decoder.read_info().unwrap();
+    let gamma = reader.info().source_gamma.map(|g| g.into_scaled());
+    assert_eq!(gamma, Some(100000));
+    let mut bytes = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut bytes).unwrap();
+    assert_eq!((info.width, info.height), (2, 2));
+    assert_eq!(info.bit_depth, png::BitDepth::Sixteen);
+    let samples: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .collect();
+    assert_eq!(samples, expected);
+
+    #[cfg(feature = "tiff")]
+    {
+        let tiff_path = std::env::temp_dir().join("ruxel_ut_canvas_16.tiff");
+        canvas
+            .write_to_tiff16(&tiff_path, OutputTransform::Linear)
+            .unwrap();
+        let mut decoder = tiff::decoder::Decoder::new(File::open(&tiff_path).unwrap()).unwrap();
+        assert_eq!(decoder.dimensions().unwrap(), (2, 2));
+        match decoder.read_image().unwrap() {
+            tiff::decoder::DecodingResult::U16(samples) => assert_eq!(samples, expected),
+            _ => panic!("TIFF image is not 16-bit"),
+        }
+        let missing = std::env::temp_dir().join("ruxel_ut_missing_dir/canvas_16.tiff");
+        assert_eq!(
+            canvas.write_to_tiff16(&missing, OutputTransform::Linear),
+            Err(ImageError::Io(std::io::ErrorKind::NotFound))
+        );
+    }
+}
+
+#[test]
+// 16-bit PNG files are encoded and tagged with the output transform
+fn ut_canvas_16_bit_color_space() {
+    let mut canvas = Canvas::new(1, 1);
+    canvas.write_pixel(Pixel::new(0, 0, ColorRgb::new(0.5, 0.25, 0.0)));
+    assert_eq!(
+        canvas.rgb16_samples(OutputTransform::Srgb),
+        canvas
+            .encoded(OutputTransform::Srgb)
+            .rgb16_samples(OutputTransform::Linear)
+    );
+
+    let path = std::env::temp_dir().join("ruxel_ut_canvas_16_p3.png");
+    canvas
+        .write_to_png16(&path, OutputTransform::DisplayP3)
+        .unwrap();
+    let reader = png::Decoder::new(File::open(&path).unwrap())
+        .read_info()
+        .unwrap();
+    let info = reader.info();
+    assert_eq!(info.source_gamma.map(|g| g.into_scaled()), Some(45455));
+    let red = info.source_chromaticities.unwrap().red;
+    assert_eq!((red.0.into_scaled(), red.1.into_scaled()), (68000, 32000));
+
+    let empty_path = std::env::temp_dir().join("ruxel_ut_canvas_16_empty.png");
+    assert_eq!(
+        Canvas::new(0, 4).write_to_png16(&empty_path, OutputTransform::Srgb),
+        Err(ImageError::Empty)
+    );
+
+    let missing = std::env::temp_dir().join("ruxel_ut_missing_dir/canvas_16.png");
+    assert_eq!(
+        canvas.write_to_png16(&missing, OutputTransform::Srgb),
+        Err(ImageError::Io(std::io::ErrorKind::NotFound))
+    );
+}
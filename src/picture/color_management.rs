@@ -0,0 +1,101 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Minimal color management: output transforms from the linear Rec. 709 / sRGB primaries the
+renderer works in, to the encoded values expected by displays.
+*/
+use crate::picture::colors::ColorRgb;
+
+// Color Management Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Conversion of linear colors from the sRGB / Rec. 709 primaries to the Display P3 ones,
+/// both with a D65 white point. Rows give the P3 red, green and blue components.
+const LINEAR_SRGB_TO_DISPLAY_P3: [[f64; 3]; 3] = [
+    [0.822_462_1, 0.177_538_0, 0.0],
+    [0.033_194_1, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+/// Selects the output transform applied to linear colors before they are exported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputTransform {
+    /// No transform: the linear values are written as they are.
+    #[default]
+    Linear,
+    /// sRGB transfer function, for regular computer displays.
+    Srgb,
+    /// Rec. 709 (BT.709) transfer function, for HD video.
+    Rec709,
+    /// Display P3 primaries with the sRGB transfer function, for wide-gamut displays.
+    DisplayP3,
+}
+
+impl OutputTransform {
+    /// Returns the encoded color of the linear color 'c'. Negative components are clamped
+    /// to '0'; values above '1' are left for the exporter to clamp.
+    pub fn apply(&self, c: ColorRgb) -> ColorRgb {
+        let c = ColorRgb {
+            r: c.r.max(0.0),
+            g: c.g.max(0.0),
+            b: c.b.max(0.0),
+        };
+        match self {
+            OutputTransform::Linear => c,
+            OutputTransform::Srgb => map_channels(c, srgb_encode),
+            OutputTransform::Rec709 => map_channels(c, rec709_encode),
+            OutputTransform::DisplayP3 => {
+                let m = LINEAR_SRGB_TO_DISPLAY_P3;
+                let p3 = ColorRgb {
+                    r: m[0][0] * c.r + m[0][1] * c.g + m[0][2] * c.b,
+                    g: m[1][0] * c.r + m[1][1] * c.g + m[1][2] * c.b,
+                    b: m[2][0] * c.r + m[2][1] * c.g + m[2][2] * c.b,
+                };
+                map_channels(p3, srgb_encode)
+            }
+        }
+    }
+}
+
+// Applies 'f' to every component of a color.
+fn map_channels(c: ColorRgb, f: fn(f64) -> f64) -> ColorRgb {
+    ColorRgb {
+        r: f(c.r),
+        g: f(c.g),
+        b: f(c.b),
+    }
+}
+
+// sRGB transfer function (IEC 61966-2-1).
+fn srgb_encode(v: f64) -> f64 {
+    if v <= 0.003_130_8 {
+        12.92 * v
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Inverse of the sRGB transfer function, from display encoded to linear values.
+pub(crate) fn srgb_decode(v: f64) -> f64 {
+    if v <= 0.040_45 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Rec. 709 transfer function (ITU-R BT.709).
+fn rec709_encode(v: f64) -> f64 {
+    if v < 0.018 {
+        4.5 * v
+    } else {
+        1.099 * v.powf(0.45) - 0.099
+    }
+}
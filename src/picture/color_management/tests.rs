@@ -0,0 +1,63 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the output transforms.
+
+use super::*;
+use crate::picture::canvas::{Canvas, Pixel};
+use crate::picture::colors::ColorInit;
+
+#[test]
+// Mid grey is encoded by each transfer function, black and white are preserved
+fn ut_color_management_transfer_functions() {
+    let grey = ColorRgb::new(0.5, 0.5, 0.5);
+    assert_eq!(OutputTransform::Linear.apply(grey), grey);
+    assert_eq!(
+        OutputTransform::Srgb.apply(grey),
+        ColorRgb::new(0.73536, 0.73536, 0.73536)
+    );
+    assert_eq!(
+        OutputTransform::Rec709.apply(grey),
+        ColorRgb::new(0.70552, 0.70552, 0.70552)
+    );
+    for t in [
+        OutputTransform::Srgb,
+        OutputTransform::Rec709,
+        OutputTransform::DisplayP3,
+    ] {
+        assert_eq!(t.apply(ColorRgb::BLACK), ColorRgb::BLACK);
+        assert_eq!(t.apply(ColorRgb::WHITE), ColorRgb::WHITE);
+    }
+    assert_eq!(
+        OutputTransform::Srgb.apply(ColorRgb::new(-1.0, 0.0, 0.0)),
+        ColorRgb::BLACK
+    );
+}
+
+#[test]
+// Pure sRGB red lies inside the wider Display P3 gamut
+fn ut_color_management_display_p3() {
+    let red = OutputTransform::DisplayP3.apply(ColorRgb::red());
+    assert_eq!(red, ColorRgb::new(0.91749, 0.20029, 0.13856));
+}
+
+#[test]
+// The output transform encodes a copy of the Canvas at export, leaving it linear
+fn ut_color_management_canvas() {
+    let mut canvas = Canvas::new(2, 1);
+    canvas.write_pixel(Pixel::new(1, 0, ColorRgb::new(0.5, 0.0, 0.25)));
+    let statistics = canvas.statistics();
+    let encoded = canvas.encoded(OutputTransform::Srgb);
+    assert_eq!(
+        encoded.read_pixel(1, 0),
+        OutputTransform::Srgb.apply(ColorRgb::new(0.5, 0.0, 0.25))
+    );
+    assert_eq!(encoded.read_pixel(0, 0), ColorRgb::BLACK);
+    assert_eq!(canvas.read_pixel(1, 0), ColorRgb::new(0.5, 0.0, 0.25));
+    assert_eq!(canvas.statistics(), statistics);
+}
@@ -10,7 +10,7 @@
 Data structures and operations for the Colors type
 */
 use std::{
-    cmp::{Eq, PartialEq},
+    cmp::PartialEq,
     fmt::Display,
     ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
@@ -21,7 +21,7 @@ use crate::geometry::EPSILON;
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Default)]
 /// Represent a color in Red, Green and Blue format
 pub struct ColorRgb {
     /// Red component
@@ -32,6 +32,19 @@ pub struct ColorRgb {
     pub b: f64,
 }
 
+#[derive(Debug, Copy, Clone, Default)]
+/// Represent a color in Red, Green and Blue format with an Alpha (coverage) component
+pub struct ColorRgba {
+    /// Red component
+    pub r: f64,
+    /// Green component
+    pub g: f64,
+    /// Blue component
+    pub b: f64,
+    /// Alpha component, from 0 (transparent) to 1 (opaque)
+    pub a: f64,
+}
+
 impl Display for ColorRgb {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = format!("({:^2.2},{:^2.2},{:^2.2})", self.r, self.g, self.b);
@@ -39,42 +52,164 @@ impl Display for ColorRgb {
     }
 }
 
-impl Default for ColorRgb {
-    fn default() -> Self {
-        Self {
-            r: Default::default(),
-            g: Default::default(),
-            b: Default::default(),
-        }
-    }
-}
-
 impl PartialEq for ColorRgb {
     fn eq(&self, other: &Self) -> bool {
         // self.r == other.r && self.g == other.g && self.b == other.b
         self.equal(other)
     }
+}
+
+// Same epsilon comparison as ColorRgb, including the alpha component.
+impl PartialEq for ColorRgba {
+    fn eq(&self, other: &Self) -> bool {
+        (self.r - other.r).abs() < EPSILON
+            && (self.g - other.g).abs() < EPSILON
+            && (self.b - other.b).abs() < EPSILON
+            && (self.a - other.a).abs() < EPSILON
+    }
+}
+
+// Implementation of the approx traits for ColorRgb, comparing every component.
+#[cfg(feature = "approx")]
+impl approx::AbsDiffEq for ColorRgb {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
 
-    fn ne(&self, other: &Self) -> bool {
-        !self.equal(other)
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.r.abs_diff_eq(&other.r, epsilon)
+            && self.g.abs_diff_eq(&other.g, epsilon)
+            && self.b.abs_diff_eq(&other.b, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl approx::RelativeEq for ColorRgb {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.r.relative_eq(&other.r, epsilon, max_relative)
+            && self.g.relative_eq(&other.g, epsilon, max_relative)
+            && self.b.relative_eq(&other.b, epsilon, max_relative)
+    }
+}
+
+/**
+Quantized color with 8 bits per channel.
+Unlike ColorRgb, which compares with an epsilon, ColorKey has exact equality and
+can be hashed and ordered, so it can be used as a HashMap or BTreeMap key for palette
+extraction and color deduplication.
+*/
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ColorKey {
+    /// Red component
+    pub r: u8,
+    /// Green component
+    pub g: u8,
+    /// Blue component
+    pub b: u8,
+}
+
+impl From<ColorRgb> for ColorKey {
+    /// Clamps every component to [0, 1] and rounds it to the nearest 8-bit value.
+    fn from(c: ColorRgb) -> Self {
+        let quantize = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        ColorKey {
+            r: quantize(c.r),
+            g: quantize(c.g),
+            b: quantize(c.b),
+        }
+    }
+}
+
+impl From<ColorKey> for ColorRgb {
+    fn from(k: ColorKey) -> Self {
+        ColorRgb {
+            r: k.r as f64 / 255.0,
+            g: k.g as f64 / 255.0,
+            b: k.b as f64 / 255.0,
+        }
+    }
+}
+
+impl From<ColorRgb> for ColorRgba {
+    fn from(c: ColorRgb) -> Self {
+        ColorRgba {
+            r: c.r,
+            g: c.g,
+            b: c.b,
+            a: 1.0,
+        }
+    }
+}
+
+impl Display for ColorRgba {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "({:^2.2},{:^2.2},{:^2.2},{:^2.2})",
+            self.r, self.g, self.b, self.a
+        );
+        f.write_str(&s)
+    }
+}
+
+impl Display for ColorKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+        f.write_str(&s)
+    }
+}
+
+impl ColorRgb {
+    /// Black color, with all the components set to 0.
+    pub const BLACK: ColorRgb = ColorRgb {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+    };
+
+    /// White color, with all the components set to 1.
+    pub const WHITE: ColorRgb = ColorRgb {
+        r: 1.0,
+        g: 1.0,
+        b: 1.0,
+    };
+
+    /// Returns the relative luminance of the color using the Rec. 709 coefficients.
+    pub fn luminance(&self) -> f64 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Linearly interpolates between the colors 'a' (t = 0) and 'b' (t = 1).
+    pub fn lerp(a: ColorRgb, b: ColorRgb, t: f64) -> ColorRgb {
+        ColorRgb {
+            r: a.r + (b.r - a.r) * t,
+            g: a.g + (b.g - a.g) * t,
+            b: a.b + (b.b - a.b) * t,
+        }
     }
 }
-impl Eq for ColorRgb {}
 
 /// Trait that enables Color initialization
 pub trait ColorInit<T> {
     /// .
-    fn new(r: f64, g: f64, b: f64) -> T;
+    fn new(r: f64, g: f64, b: f64) -> Self;
     /// .
-    fn red() -> T;
+    fn red() -> Self;
     /// .
-    fn green() -> T;
+    fn green() -> Self;
     /// .
-    fn blue() -> T;
+    fn blue() -> Self;
     /// .
-    fn black() -> T;
+    #[deprecated(note = "use the ColorRgb::BLACK associated const")]
+    fn black() -> Self;
     /// .
-    fn white() -> T;
+    #[deprecated(note = "use the ColorRgb::WHITE associated const")]
+    fn white() -> Self;
     /// .
     fn equal(self, other: &T) -> bool;
 }
@@ -125,14 +260,9 @@ impl ColorInit<ColorRgb> for ColorRgb {
     }
 
     fn equal(self, other: &ColorRgb) -> bool {
-        if (self.r - other.r).abs() < EPSILON
+        (self.r - other.r).abs() < EPSILON
             && (self.g - other.g).abs() < EPSILON
             && (self.b - other.b).abs() < EPSILON
-        {
-            true
-        } else {
-            false
-        }
     }
 }
 
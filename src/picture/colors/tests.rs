@@ -6,12 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-/// Unit testing for the Colors type
-
-/// Unit testing for the Colors types
+// Unit testing for the Colors types
 use super::*;
 
 #[test]
+#[allow(deprecated)]
 // This test checks for the integrity of the Colors initialization
 fn ut_colors_initialization() {
     let red = ColorRgb::red();
@@ -27,7 +26,9 @@ fn ut_colors_initialization() {
                 ..Default::default()
             }
             && white == ColorRgb::new(1.0, 1.0, 1.0)
-    )
+    );
+    assert_eq!(black, ColorRgb::BLACK);
+    assert_eq!(white, ColorRgb::WHITE);
 }
 #[test]
 // This test checks for the integrity of Add, AddAssing, Sub, SubAssign, Mul and MulAssing
@@ -48,3 +49,65 @@ fn ut_colors_operator_overloading() {
     c3 *= c4;
     assert!(c3 != ColorRgb::new(0.9, 0.2, 0.04));
 }
+
+#[test]
+// This test checks the quantization and hashing of ColorKey
+fn ut_colors_key_quantization() {
+    use std::collections::HashSet;
+
+    let key = ColorKey::from(ColorRgb::new(1.5, 0.5, -0.2));
+    assert_eq!(
+        key,
+        ColorKey {
+            r: 255,
+            g: 128,
+            b: 0
+        }
+    );
+    assert_eq!(format!("{}", key), "#ff8000");
+    assert_eq!(ColorRgb::from(key), ColorRgb::new(1.0, 128.0 / 255.0, 0.0));
+
+    // Colors within the same quantization step deduplicate
+    let set: HashSet<ColorKey> = [
+        ColorRgb::new(0.2, 0.4, 0.6),
+        ColorRgb::new(0.2001, 0.4, 0.6),
+        ColorRgb::red(),
+    ]
+    .iter()
+    .map(|&c| ColorKey::from(c))
+    .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+// Interpolating between two colors
+fn ut_colors_lerp() {
+    let a = ColorRgb::BLACK;
+    let b = ColorRgb::new(1.0, 0.5, 0.25);
+    assert_eq!(ColorRgb::lerp(a, b, 0.0), a);
+    assert_eq!(ColorRgb::lerp(a, b, 1.0), b);
+    assert_eq!(ColorRgb::lerp(a, b, 0.5), ColorRgb::new(0.5, 0.25, 0.125));
+}
+
+#[test]
+// Colors with alpha compare with the same epsilon as colors without it
+fn ut_colors_rgba_equality() {
+    let c = ColorRgba::from(ColorRgb::new(0.1, 0.2, 0.3));
+    let close = ColorRgba {
+        r: 0.1 + EPSILON / 2.0,
+        ..c
+    };
+    assert_eq!(c, close);
+    assert_ne!(c, ColorRgba { a: 0.5, ..c });
+}
+
+#[test]
+#[cfg(feature = "approx")]
+// Tolerance-based comparisons with the approx crate
+fn ut_colors_approx() {
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+
+    let c = ColorRgb::new(0.5, 0.5, 0.5);
+    assert_abs_diff_eq!(c, ColorRgb::new(0.5, 0.5, 0.51), epsilon = 0.1);
+    assert_abs_diff_ne!(c, ColorRgb::new(0.5, 0.5, 0.51));
+}
@@ -0,0 +1,93 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Comparison of two Canvas: per-pixel difference images and a perceptual similarity score.
+*/
+use crate::picture::canvas::Canvas;
+use crate::picture::colors::*;
+
+// Diff Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Side of the square windows over which the SSIM score is computed.
+pub const SSIM_WINDOW: usize = 8;
+
+/// Returns a Canvas holding the per-pixel absolute difference of 'a' and 'b',
+/// or None if their dimensions differ.
+pub fn difference(a: &Canvas, b: &Canvas) -> Option<Canvas> {
+    if a.width != b.width || a.height != b.height {
+        return None;
+    }
+    let mut res = Canvas::new(a.width, a.height);
+    for x in 0..a.width {
+        for y in 0..a.height {
+            let (ca, cb) = (a.data[x][y], b.data[x][y]);
+            res.data[x][y] = ColorRgb::new(
+                (ca.r - cb.r).abs(),
+                (ca.g - cb.g).abs(),
+                (ca.b - cb.b).abs(),
+            );
+        }
+    }
+    Some(res)
+}
+
+/**
+Returns the mean structural similarity (SSIM) of the luminance of 'a' and 'b',
+computed over [`SSIM_WINDOW`] sized windows, or None if their dimensions differ.
+A score of 1.0 means the images are identical; lower scores mean they are
+perceptually further apart.
+*/
+pub fn ssim(a: &Canvas, b: &Canvas) -> Option<f64> {
+    if a.width != b.width || a.height != b.height {
+        return None;
+    }
+    if a.width == 0 || a.height == 0 {
+        return Some(1.0);
+    }
+
+    // Stabilizing constants for a dynamic range of 1.0.
+    let c1 = (0.01f64).powi(2);
+    let c2 = (0.03f64).powi(2);
+
+    let mut total = 0.0;
+    let mut windows = 0;
+    for wx in (0..a.width).step_by(SSIM_WINDOW) {
+        for wy in (0..a.height).step_by(SSIM_WINDOW) {
+            let mut la = vec![];
+            let mut lb = vec![];
+            for x in wx..(wx + SSIM_WINDOW).min(a.width) {
+                for y in wy..(wy + SSIM_WINDOW).min(a.height) {
+                    la.push(a.data[x][y].luminance());
+                    lb.push(b.data[x][y].luminance());
+                }
+            }
+            let n = la.len() as f64;
+            let mean_a = la.iter().sum::<f64>() / n;
+            let mean_b = lb.iter().sum::<f64>() / n;
+            let mut var_a = 0.0;
+            let mut var_b = 0.0;
+            let mut covar = 0.0;
+            for (va, vb) in la.iter().zip(lb.iter()) {
+                var_a += (va - mean_a).powi(2);
+                var_b += (vb - mean_b).powi(2);
+                covar += (va - mean_a) * (vb - mean_b);
+            }
+            var_a /= n;
+            var_b /= n;
+            covar /= n;
+
+            total += ((2.0 * mean_a * mean_b + c1) * (2.0 * covar + c2))
+                / ((mean_a.powi(2) + mean_b.powi(2) + c1) * (var_a + var_b + c2));
+            windows += 1;
+        }
+    }
+    Some(total / windows as f64)
+}
@@ -0,0 +1,57 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit testing for the Diff functions
+use super::*;
+use crate::picture::canvas::Pixel;
+
+fn gradient(width: usize, height: usize) -> Canvas {
+    let mut canvas = Canvas::new(width, height);
+    for x in 0..width {
+        for y in 0..height {
+            let v = (x + y) as f64 / (width + height) as f64;
+            canvas.write_pixel(Pixel::new(x, y, ColorRgb::new(v, v, v)));
+        }
+    }
+    canvas
+}
+
+#[test]
+// Difference image between two canvas
+fn ut_diff_difference() {
+    let a = gradient(10, 10);
+    let mut b = gradient(10, 10);
+    b.write_pixel(Pixel::new(3, 4, ColorRgb::red()));
+
+    let d = difference(&a, &b).unwrap();
+    assert_eq!(d.data[0][0], ColorRgb::BLACK);
+    let c = a.data[3][5];
+    assert_eq!(d.data[3][5], ColorRgb::new(1.0 - c.r, c.g, c.b));
+    assert!(difference(&a, &Canvas::new(5, 10)).is_none());
+}
+
+#[test]
+// Perceptual similarity score between two canvas
+fn ut_diff_ssim() {
+    let a = gradient(20, 12);
+    assert!((ssim(&a, &a).unwrap() - 1.0).abs() < 1e-9);
+
+    let mut noisy = gradient(20, 12);
+    for x in (0..20).step_by(3) {
+        noisy.write_pixel(Pixel::new(x, 5, ColorRgb::WHITE));
+    }
+    let mut inverted = gradient(20, 12);
+    for color in inverted.data.iter_mut().flatten() {
+        *color = ColorRgb::WHITE - *color;
+    }
+    let s_noisy = ssim(&a, &noisy).unwrap();
+    let s_inverted = ssim(&a, &inverted).unwrap();
+    assert!(s_noisy < 1.0);
+    assert!(s_inverted < s_noisy);
+    assert!(ssim(&a, &Canvas::new(1, 1)).is_none());
+}
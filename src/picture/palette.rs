@@ -0,0 +1,306 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Color quantization and the IndexedImage type, exported as GIF or PNG-8.
+*/
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use crate::picture::canvas::Canvas;
+use crate::picture::colors::*;
+
+// Palette Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Maximum number of colors in the palette of an IndexedImage.
+pub const MAX_PALETTE_COLORS: usize = 256;
+
+/// Errors returned when an image cannot be written to a file format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// The image has no pixels, which the file formats cannot store.
+    Empty,
+    /// The image has more pixels per side than the file format can store.
+    TooLarge {
+        /// Width of the image.
+        width: usize,
+        /// Height of the image.
+        height: usize,
+    },
+    /// A frame does not have the dimensions of the first frame of its Animation.
+    FrameSize {
+        /// Width of the rejected frame.
+        width: usize,
+        /// Height of the rejected frame.
+        height: usize,
+    },
+    /// The file could not be created or written.
+    Io(io::ErrorKind),
+    /// The encoder of the file format rejected the image.
+    Encode(String),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageError::Empty => f.write_str("Image cannot be written: it has no pixels"),
+            ImageError::TooLarge { width, height } => f.write_str(&format!(
+                "Image cannot be written: {} x {} is too large for the format",
+                width, height
+            )),
+            ImageError::FrameSize { width, height } => f.write_str(&format!(
+                "Animation frame of {} x {} does not match the size of the first frame",
+                width, height
+            )),
+            ImageError::Io(kind) => f.write_str(&format!("Image file write failed: {}", kind)),
+            ImageError::Encode(message) => {
+                f.write_str(&format!("Image encoding failed: {}", message))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+impl From<io::Error> for ImageError {
+    fn from(error: io::Error) -> Self {
+        ImageError::Io(error.kind())
+    }
+}
+
+impl From<gif::EncodingError> for ImageError {
+    fn from(error: gif::EncodingError) -> Self {
+        match error {
+            gif::EncodingError::Io(error) => error.into(),
+            error => ImageError::Encode(error.to_string()),
+        }
+    }
+}
+
+impl From<png::EncodingError> for ImageError {
+    fn from(error: png::EncodingError) -> Self {
+        match error {
+            png::EncodingError::IoError(error) => error.into(),
+            error => ImageError::Encode(error.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "tiff")]
+impl From<tiff::TiffError> for ImageError {
+    fn from(error: tiff::TiffError) -> Self {
+        match error {
+            tiff::TiffError::IoError(error) => error.into(),
+            error => ImageError::Encode(error.to_string()),
+        }
+    }
+}
+
+// Returns the dimensions of an image as the integer type of a file format header.
+pub(crate) fn header_dimensions<T: TryFrom<usize>>(
+    width: usize,
+    height: usize,
+) -> Result<(T, T), ImageError> {
+    if width == 0 || height == 0 {
+        return Err(ImageError::Empty);
+    }
+    match (T::try_from(width), T::try_from(height)) {
+        (Ok(w), Ok(h)) => Ok((w, h)),
+        _ => Err(ImageError::TooLarge { width, height }),
+    }
+}
+
+/// Image made of a palette of colors and one palette index per pixel.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexedImage {
+    /// Width of the image.
+    pub width: usize,
+    /// Height of the image.
+    pub height: usize,
+    /// Colors of the palette.
+    pub palette: Vec<ColorKey>,
+    /// Palette index of every pixel, row by row starting from the top-left corner.
+    pub indices: Vec<u8>,
+}
+
+// Box of colors of the median cut algorithm, with the pixel count of each color.
+type ColorBox = Vec<(ColorKey, usize)>;
+
+fn channel(c: &ColorKey, ch: usize) -> u8 {
+    match ch {
+        0 => c.r,
+        1 => c.g,
+        _ => c.b,
+    }
+}
+
+// Returns the channel with the widest range in the box and the range itself.
+fn widest_channel(b: &ColorBox) -> (usize, u8) {
+    (0..3)
+        .map(|ch| {
+            let min = b.iter().map(|(c, _)| channel(c, ch)).min().unwrap_or(0);
+            let max = b.iter().map(|(c, _)| channel(c, ch)).max().unwrap_or(0);
+            (ch, max - min)
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap()
+}
+
+// Returns the pixel-weighted average color of the box.
+fn average(b: &ColorBox) -> ColorKey {
+    let total: usize = b.iter().map(|(_, n)| n).sum();
+    let avg = |ch: usize| {
+        let sum: usize = b.iter().map(|(c, n)| channel(c, ch) as usize * n).sum();
+        ((sum as f64) / (total as f64)).round() as u8
+    };
+    ColorKey {
+        r: avg(0),
+        g: avg(1),
+        b: avg(2),
+    }
+}
+
+/// Reduces a Canvas to at most 'n_colors' colors (clamped to [1, 256]) by recursively
+/// splitting the box of colors with the widest channel range at its median.
+pub fn median_cut(canvas: &Canvas, n_colors: usize) -> IndexedImage {
+    let n_colors = n_colors.clamp(1, MAX_PALETTE_COLORS);
+
+    let mut counts: HashMap<ColorKey, usize> = HashMap::new();
+    for color in canvas.data.iter().flatten() {
+        *counts.entry(ColorKey::from(*color)).or_insert(0) += 1;
+    }
+    let mut initial: ColorBox = counts.into_iter().collect();
+    initial.sort();
+
+    let mut boxes: Vec<ColorBox> = vec![];
+    if !initial.is_empty() {
+        boxes.push(initial);
+    }
+    while boxes.len() < n_colors {
+        // Split the box with the widest range; stop when every box holds a single color.
+        let (idx, (ch, range)) = match boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (i, widest_channel(b)))
+            .max_by_key(|&(_, (_, range))| range)
+        {
+            Some(found) => found,
+            None => break,
+        };
+        if range == 0 {
+            break;
+        }
+        let mut b = boxes.swap_remove(idx);
+        b.sort_by_key(|(c, _)| channel(c, ch));
+        let total: usize = b.iter().map(|(_, n)| n).sum();
+        let mut acc = 0;
+        let mut split = 1;
+        for (i, (_, n)) in b.iter().enumerate() {
+            acc += n;
+            if acc * 2 >= total {
+                split = (i + 1).clamp(1, b.len() - 1);
+                break;
+            }
+        }
+        let upper = b.split_off(split);
+        boxes.push(b);
+        boxes.push(upper);
+    }
+
+    let mut lookup: HashMap<ColorKey, u8> = HashMap::new();
+    let mut palette = vec![];
+    for (i, b) in boxes.iter().enumerate() {
+        palette.push(average(b));
+        for (c, _) in b {
+            lookup.insert(*c, i as u8);
+        }
+    }
+
+    let mut indices = Vec::with_capacity(canvas.width * canvas.height);
+    for y in 0..canvas.height {
+        for x in 0..canvas.width {
+            indices.push(lookup[&ColorKey::from(canvas.data[x][y])]);
+        }
+    }
+
+    IndexedImage {
+        width: canvas.width,
+        height: canvas.height,
+        palette,
+        indices,
+    }
+}
+
+impl IndexedImage {
+    /// Returns the palette as a flat list of RGB bytes.
+    pub fn palette_bytes(&self) -> Vec<u8> {
+        self.palette.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    }
+
+    /// Returns a Canvas with the palette colors of every pixel.
+    pub fn to_canvas(&self) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let index = self.indices[y * self.width + x] as usize;
+                canvas.data[x][y] = ColorRgb::from(self.palette[index]);
+            }
+        }
+        canvas
+    }
+
+    /// Writes the image to a GIF file.
+    /// Returns an error, without creating the file, for empty images and images larger
+    /// than 65535 pixels per side.
+    pub fn write_to_gif(&self, file_name: &Path) -> Result<(), ImageError> {
+        let (width, height) = header_dimensions::<u16>(self.width, self.height)?;
+        let image = File::create(file_name)?;
+        let mut encoder =
+            gif::Encoder::new(BufWriter::new(image), width, height, &self.palette_bytes())?;
+        let frame = gif::Frame {
+            width,
+            height,
+            buffer: Cow::Borrowed(&self.indices),
+            ..Default::default()
+        };
+        encoder.write_frame(&frame)?;
+        Ok(())
+    }
+
+    /// Writes the image to an indexed 8-bit PNG file.
+    /// Returns an error, without creating the file, for empty images.
+    pub fn write_to_png(&self, file_name: &Path) -> Result<(), ImageError> {
+        let (width, height) = header_dimensions::<u32>(self.width, self.height)?;
+        let image = File::create(file_name)?;
+        let mut encoder = png::Encoder::new(BufWriter::new(image), width, height);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(self.palette_bytes());
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.indices)?;
+        Ok(())
+    }
+}
+
+impl Display for IndexedImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "IndexedImage - \n width : height [{} : {}] colors: {}\n",
+            self.width,
+            self.height,
+            self.palette.len()
+        );
+        f.write_str(&s)
+    }
+}
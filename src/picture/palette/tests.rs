@@ -0,0 +1,106 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit testing for the Palette types
+use super::*;
+use crate::picture::canvas::Pixel;
+
+fn stripes() -> Canvas {
+    let mut canvas = Canvas::new(6, 4);
+    for x in 0..6 {
+        for y in 0..4 {
+            let color = match x / 2 {
+                0 => ColorRgb::red(),
+                1 => ColorRgb::new(0.8, 0.0, 0.2),
+                _ => ColorRgb::blue(),
+            };
+            canvas.write_pixel(Pixel::new(x, y, color));
+        }
+    }
+    canvas
+}
+
+#[test]
+// Quantizing a canvas keeps exact colors when the palette is large enough
+fn ut_palette_quantize_exact() {
+    let canvas = stripes();
+    let image = canvas.quantize(16);
+    println!("{}", image);
+    assert_eq!(image.palette.len(), 3);
+    assert_eq!(image.indices.len(), 24);
+    assert_eq!(image.to_canvas().data, canvas.data);
+}
+
+#[test]
+// Quantizing a canvas merges the closest colors
+fn ut_palette_quantize_reduced() {
+    let image = stripes().quantize(2);
+    assert_eq!(image.palette.len(), 2);
+    assert_eq!(image.indices[0], image.indices[2]);
+    assert_ne!(image.indices[0], image.indices[4]);
+    assert!(image.palette.contains(&ColorKey::from(ColorRgb::blue())));
+    assert_eq!(Canvas::new(0, 0).quantize(8).palette.len(), 0);
+}
+
+#[test]
+// Indexed images are written as GIF and PNG-8 files
+fn ut_palette_indexed_export() {
+    let image = stripes().quantize(4);
+    let gif_path = std::env::temp_dir().join("ruxel_ut_palette.gif");
+    let png_path = std::env::temp_dir().join("ruxel_ut_palette.png");
+    image.write_to_gif(&gif_path).unwrap();
+    image.write_to_png(&png_path).unwrap();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+    let mut decoder = options.read_info(File::open(&gif_path).unwrap()).unwrap();
+    let frame = decoder.read_next_frame().unwrap().unwrap();
+    assert_eq!((frame.width, frame.height), (6, 4));
+    assert_eq!(frame.buffer.to_vec(), image.indices);
+
+    let decoder = png::Decoder::new(File::open(&png_path).unwrap());
+    let reader = decoder.read_info().unwrap();
+    let info = reader.info();
+    assert_eq!((info.width, info.height), (6, 4));
+    assert_eq!(info.color_type, png::ColorType::Indexed);
+    assert_eq!(info.palette.as_deref(), Some(&image.palette_bytes()[..]));
+}
+
+#[test]
+// Images the file formats cannot store are rejected
+fn ut_palette_export_errors() {
+    let path = std::env::temp_dir().join("ruxel_ut_palette_error.gif");
+    let empty = Canvas::new(0, 0).quantize(4);
+    assert_eq!(empty.write_to_gif(&path), Err(ImageError::Empty));
+    assert_eq!(empty.write_to_png(&path), Err(ImageError::Empty));
+
+    let wide = IndexedImage {
+        width: 70_000,
+        height: 1,
+        ..Default::default()
+    };
+    let error = wide.write_to_gif(&path).unwrap_err();
+    assert_eq!(
+        error,
+        ImageError::TooLarge {
+            width: 70_000,
+            height: 1
+        }
+    );
+    println!("{}", error);
+
+    // File errors are returned instead of panicking
+    let image = Canvas::new(2, 2).quantize(4);
+    let missing = std::env::temp_dir().join("ruxel_ut_missing_dir/palette.png");
+    let error = image.write_to_png(&missing).unwrap_err();
+    assert_eq!(error, ImageError::Io(std::io::ErrorKind::NotFound));
+    assert_eq!(
+        image.write_to_gif(&missing.with_extension("gif")),
+        Err(error)
+    );
+}
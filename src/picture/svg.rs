@@ -0,0 +1,189 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Data structure and operations for SvgCanvas: a vector backend for debug renders that records
+drawn primitives instead of rasterizing them.
+*/
+use std::fmt::Display;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use crate::picture::colors::*;
+
+// SvgCanvas Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Primitive recorded by an [`SvgCanvas`], in canvas coordinates with the origin
+/// at the bottom-left corner like [`Pixel`](crate::picture::canvas::Pixel).
+#[derive(Debug, Clone, PartialEq)]
+pub enum SvgShape {
+    /// Straight segment between two points.
+    Line {
+        /// Start of the segment.
+        from: (f64, f64),
+        /// End of the segment.
+        to: (f64, f64),
+    },
+    /// Open path through a sequence of points.
+    Polyline(Vec<(f64, f64)>),
+    /// Filled circle, e.g. a point marker.
+    Circle {
+        /// Center of the circle.
+        center: (f64, f64),
+        /// Radius of the circle.
+        radius: f64,
+    },
+}
+
+/// Primitive of an [`SvgCanvas`] together with its color and stroke width.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgElement {
+    /// Recorded primitive.
+    pub shape: SvgShape,
+    /// Stroke color of lines and fill color of circles.
+    pub color: ColorRgb,
+    /// Width of the stroke.
+    pub stroke_width: f64,
+}
+
+/// Defines a vector Canvas with a width, a height, a background color and the list of
+/// recorded primitives.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SvgCanvas {
+    /// Width of the SvgCanvas.
+    pub width: usize,
+    /// Height of the SvgCanvas.
+    pub height: usize,
+    /// Color filling the whole SvgCanvas behind the primitives.
+    pub background: ColorRgb,
+    /// Primitives in drawing order.
+    pub elements: Vec<SvgElement>,
+}
+
+impl SvgCanvas {
+    /// Creates a new empty [`SvgCanvas`] with a black background.
+    pub fn new(width: usize, height: usize) -> SvgCanvas {
+        SvgCanvas {
+            width,
+            height,
+            background: ColorRgb::BLACK,
+            elements: vec![],
+        }
+    }
+
+    /// Records a line segment between 'from' and 'to'.
+    pub fn line(&mut self, from: (f64, f64), to: (f64, f64), color: ColorRgb, stroke_width: f64) {
+        self.elements.push(SvgElement {
+            shape: SvgShape::Line { from, to },
+            color,
+            stroke_width,
+        });
+    }
+
+    /// Records an open path through 'points'.
+    pub fn polyline(&mut self, points: &[(f64, f64)], color: ColorRgb, stroke_width: f64) {
+        self.elements.push(SvgElement {
+            shape: SvgShape::Polyline(points.to_vec()),
+            color,
+            stroke_width,
+        });
+    }
+
+    /// Records a filled circle.
+    pub fn circle(&mut self, center: (f64, f64), radius: f64, color: ColorRgb) {
+        self.elements.push(SvgElement {
+            shape: SvgShape::Circle { center, radius },
+            color,
+            stroke_width: 0.0,
+        });
+    }
+
+    // Converts canvas coordinates to SVG coordinates, where y grows downwards.
+    fn to_svg_coords(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        (x, self.height as f64 - y)
+    }
+
+    /// Returns the SvgCanvas as an SVG document.
+    pub fn to_svg_string(&self) -> String {
+        let mut s = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+            w = self.width,
+            h = self.height
+        );
+        s.push_str(&format!(
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>\n",
+            ColorKey::from(self.background)
+        ));
+        for element in &self.elements {
+            let color = ColorKey::from(element.color);
+            let line = match &element.shape {
+                SvgShape::Line { from, to } => {
+                    let (x1, y1) = self.to_svg_coords(*from);
+                    let (x2, y2) = self.to_svg_coords(*to);
+                    format!(
+                        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                        x1, y1, x2, y2, color, element.stroke_width
+                    )
+                }
+                SvgShape::Polyline(points) => {
+                    let points: Vec<String> = points
+                        .iter()
+                        .map(|p| {
+                            let (x, y) = self.to_svg_coords(*p);
+                            format!("{},{}", x, y)
+                        })
+                        .collect();
+                    format!(
+                        "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\"/>\n",
+                        points.join(" "),
+                        color,
+                        element.stroke_width
+                    )
+                }
+                SvgShape::Circle { center, radius } => {
+                    let (cx, cy) = self.to_svg_coords(*center);
+                    format!(
+                        "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+                        cx, cy, radius, color
+                    )
+                }
+            };
+            s.push_str(&line);
+        }
+        s.push_str("</svg>\n");
+        s
+    }
+
+    /// Writes the SvgCanvas to an SVG file.
+    pub fn write_to_svg(&self, file_name: &Path) {
+        let mut image = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(file_name)
+            .expect("Cannot open image file");
+        image
+            .write_all(self.to_svg_string().as_bytes())
+            .expect("Write failed");
+    }
+}
+
+impl Display for SvgCanvas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "SvgCanvas - width: {} height: {} elements: {}\n",
+            self.width,
+            self.height,
+            self.elements.len()
+        );
+        f.write_str(&s)
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit testing for the SvgCanvas types
+use super::*;
+
+#[test]
+// Primitives are recorded as vector elements with the y axis flipped
+fn ut_svg_primitives() {
+    let mut svg = SvgCanvas::new(100, 50);
+    svg.line((0.0, 0.0), (100.0, 50.0), ColorRgb::red(), 1.5);
+    svg.polyline(&[(10.0, 10.0), (20.0, 40.0)], ColorRgb::green(), 1.0);
+    svg.circle((50.0, 25.0), 2.0, ColorRgb::WHITE);
+    println!("{}", svg);
+
+    let s = svg.to_svg_string();
+    assert!(s.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"50\""));
+    assert!(s.contains("<rect width=\"100%\" height=\"100%\" fill=\"#000000\"/>"));
+    assert!(s.contains(
+        "<line x1=\"0\" y1=\"50\" x2=\"100\" y2=\"0\" stroke=\"#ff0000\" stroke-width=\"1.5\"/>"
+    ));
+    assert!(s.contains("points=\"10,40 20,10\""));
+    assert!(s.contains("<circle cx=\"50\" cy=\"25\" r=\"2\" fill=\"#ffffff\"/>"));
+    assert!(s.ends_with("</svg>\n"));
+}
+
+#[test]
+// An SvgCanvas is written to an SVG file
+fn ut_svg_write_file() {
+    let mut svg = SvgCanvas::new(10, 10);
+    svg.circle((5.0, 5.0), 1.0, ColorRgb::blue());
+    let path = std::env::temp_dir().join("ruxel_ut_svg.svg");
+    svg.write_to_svg(&path);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), svg.to_svg_string());
+}
@@ -0,0 +1,163 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Data structures and operations for image Textures sampled with UV coordinates.
+*/
+use std::fmt::Display;
+
+use crate::picture::canvas::{Canvas, Pixel};
+use crate::picture::colors::*;
+
+// Texture Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Defines how UV coordinates outside of the [0, 1] range are handled.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum WrapMode {
+    /// Tiles the texture.
+    #[default]
+    Repeat,
+    /// Tiles the texture, flipping every other tile.
+    Mirror,
+    /// Extends the edge pixels of the texture.
+    Clamp,
+    /// Returns a fixed color outside of the texture.
+    Border(ColorRgb),
+}
+
+/// Transformation applied to UV coordinates before sampling: scale, then rotation
+/// (in radians, around the UV origin), then offset.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UvTransform {
+    /// Scale of the U and V coordinates.
+    pub scale: (f64, f64),
+    /// Offset added to the U and V coordinates.
+    pub offset: (f64, f64),
+    /// Rotation of the UV coordinates in radians.
+    pub rotation: f64,
+}
+
+/// Defines an image Texture sampled by UV coordinates.
+#[derive(Debug, Clone, Default)]
+pub struct ImageTexture {
+    /// Image data of the Texture.
+    pub canvas: Canvas,
+    /// Wrap mode for UV coordinates outside of the [0, 1] range.
+    pub wrap: WrapMode,
+    /// Transformation applied to UV coordinates before sampling.
+    pub transform: UvTransform,
+}
+
+impl WrapMode {
+    /// Maps a texture coordinate into the [0, 1] range, or returns None when
+    /// the coordinate falls on the border.
+    pub fn wrap(&self, t: f64) -> Option<f64> {
+        match self {
+            WrapMode::Repeat => Some(t - t.floor()),
+            WrapMode::Mirror => {
+                let period = t.rem_euclid(2.0);
+                Some(if period > 1.0 { 2.0 - period } else { period })
+            }
+            WrapMode::Clamp => Some(t.clamp(0.0, 1.0)),
+            WrapMode::Border(_) => {
+                if (0.0..=1.0).contains(&t) {
+                    Some(t)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl UvTransform {
+    /// Applies the transformation to the (u, v) coordinates.
+    pub fn apply(&self, u: f64, v: f64) -> (f64, f64) {
+        let (u, v) = (u * self.scale.0, v * self.scale.1);
+        let (sin, cos) = self.rotation.sin_cos();
+        (
+            u * cos - v * sin + self.offset.0,
+            u * sin + v * cos + self.offset.1,
+        )
+    }
+}
+
+impl Default for UvTransform {
+    fn default() -> Self {
+        Self {
+            scale: (1.0, 1.0),
+            offset: (0.0, 0.0),
+            rotation: 0.0,
+        }
+    }
+}
+
+impl ImageTexture {
+    /// Creates a new [`ImageTexture`] from a Canvas with the default wrap mode and transform.
+    pub fn new(canvas: Canvas) -> ImageTexture {
+        ImageTexture {
+            canvas,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new [`ImageTexture`] of 'width' by 'height' texels by evaluating a
+    /// procedural 'pattern' once per texel, at the (u, v) coordinates of the texel center,
+    /// so expensive patterns can be precomputed.
+    pub fn bake(
+        width: usize,
+        height: usize,
+        pattern: impl Fn(f64, f64) -> ColorRgb,
+    ) -> ImageTexture {
+        let mut canvas = Canvas::new(width, height);
+        let coord = |i: usize, n: usize| (i as f64 + 0.5) / n as f64;
+        for x in 0..width {
+            for y in 0..height {
+                let color = pattern(coord(x, width), coord(y, height));
+                canvas.write_pixel(Pixel::new(x, y, color));
+            }
+        }
+        ImageTexture::new(canvas)
+    }
+
+    /// Returns the color of the Texture at the (u, v) coordinates, where (0, 0)
+    /// is the bottom-left corner of the image.
+    #[cfg_attr(feature = "profiling", profiling::function)]
+    pub fn sample(&self, u: f64, v: f64) -> ColorRgb {
+        let (u, v) = self.transform.apply(u, v);
+        let (u, v) = match (self.wrap.wrap(u), self.wrap.wrap(v)) {
+            (Some(u), Some(v)) => (u, v),
+            _ => match self.wrap {
+                WrapMode::Border(color) => return color,
+                _ => unreachable!("Only the border mode leaves coordinates unwrapped"),
+            },
+        };
+        if self.canvas.width == 0 || self.canvas.height == 0 {
+            return ColorRgb::BLACK;
+        }
+        // Every texel covers the same 1 / n footprint; a coordinate of exactly 1 belongs to
+        // the last texel.
+        let texel = |t: f64, n: usize| ((t * n as f64).floor() as usize).min(n - 1);
+        let x = texel(u, self.canvas.width);
+        // Canvas data is stored with the first row at the top of the image.
+        let y = self.canvas.height - 1 - texel(v, self.canvas.height);
+        self.canvas.data[x][y]
+    }
+}
+
+impl Display for ImageTexture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!(
+            "ImageTexture - [{} : {}] wrap: {:?}",
+            self.canvas.width, self.canvas.height, self.wrap
+        );
+        f.write_str(&s)
+    }
+}
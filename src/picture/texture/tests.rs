@@ -0,0 +1,114 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit testing for the Texture types
+use super::*;
+use crate::picture::canvas::Pixel;
+use std::f64::consts::PI;
+
+// 2x2 texture: red bottom-left, green bottom-right, blue top-left, white top-right.
+fn quad() -> ImageTexture {
+    let mut canvas = Canvas::new(2, 2);
+    canvas.write_pixel(Pixel::new(0, 0, ColorRgb::red()));
+    canvas.write_pixel(Pixel::new(1, 0, ColorRgb::green()));
+    canvas.write_pixel(Pixel::new(0, 1, ColorRgb::blue()));
+    canvas.write_pixel(Pixel::new(1, 1, ColorRgb::WHITE));
+    ImageTexture::new(canvas)
+}
+
+#[test]
+// Wrapping of texture coordinates
+fn ut_texture_wrap_modes() {
+    assert_eq!(WrapMode::Repeat.wrap(1.25), Some(0.25));
+    assert_eq!(WrapMode::Repeat.wrap(-0.25), Some(0.75));
+    assert_eq!(WrapMode::Mirror.wrap(1.25), Some(0.75));
+    assert_eq!(WrapMode::Mirror.wrap(-0.25), Some(0.25));
+    assert_eq!(WrapMode::Clamp.wrap(1.25), Some(1.0));
+    assert_eq!(WrapMode::Border(ColorRgb::BLACK).wrap(1.25), None);
+    assert_eq!(WrapMode::Border(ColorRgb::BLACK).wrap(0.5), Some(0.5));
+}
+
+#[test]
+// Sampling a texture with the different wrap modes
+fn ut_texture_sampling() {
+    let mut t = quad();
+    println!("{}", t);
+    assert_eq!(t.sample(0.0, 0.0), ColorRgb::red());
+    assert_eq!(t.sample(0.99, 0.99), ColorRgb::WHITE);
+    assert_eq!(t.sample(1.9, 0.1), ColorRgb::green());
+
+    t.wrap = WrapMode::Mirror;
+    assert_eq!(t.sample(1.9, 0.1), ColorRgb::red());
+
+    t.wrap = WrapMode::Clamp;
+    assert_eq!(t.sample(-3.0, 5.0), ColorRgb::blue());
+
+    t.wrap = WrapMode::Border(ColorRgb::new(0.5, 0.5, 0.5));
+    assert_eq!(t.sample(1.5, 0.5), ColorRgb::new(0.5, 0.5, 0.5));
+    assert_eq!(t.sample(0.9, 0.9), ColorRgb::WHITE);
+}
+
+#[test]
+// Every texel covers the same footprint, also at the seams of a repeated texture
+fn ut_texture_texel_footprint() {
+    let mut canvas = Canvas::new(4, 1);
+    for x in 0..4 {
+        canvas.write_pixel(Pixel::new(x, 0, ColorRgb::new(x as f64, 0.0, 0.0)));
+    }
+    let mut t = ImageTexture::new(canvas);
+    let mut counts = [0; 4];
+    for i in 0..800 {
+        let u = (i as f64 + 0.5) / 400.0;
+        counts[t.sample(u, 0.5).r as usize] += 1;
+    }
+    assert_eq!(counts, [200; 4]);
+    t.wrap = WrapMode::Clamp;
+    assert_eq!(t.sample(1.0, 0.5).r, 3.0);
+}
+
+#[test]
+// UV transformations applied before sampling
+fn ut_texture_uv_transform() {
+    let mut t = quad();
+    t.transform.offset = (0.9, 0.0);
+    assert_eq!(t.sample(0.0, 0.0), ColorRgb::green());
+
+    t.transform = UvTransform {
+        scale: (2.0, 1.0),
+        ..Default::default()
+    };
+    assert_eq!(t.sample(0.45, 0.0), ColorRgb::green());
+
+    t.transform = UvTransform {
+        rotation: PI / 2.0,
+        ..Default::default()
+    };
+    let (u, v) = t.transform.apply(1.0, 0.0);
+    assert!(u.abs() < 1e-9 && (v - 1.0).abs() < 1e-9);
+}
+
+#[test]
+// Baking a procedural pattern reproduces it when the texture is sampled
+fn ut_texture_bake() {
+    let checker = |u: f64, v: f64| {
+        if ((u * 4.0).floor() + (v * 4.0).floor()) as i64 % 2 == 0 {
+            ColorRgb::BLACK
+        } else {
+            ColorRgb::WHITE
+        }
+    };
+    let t = ImageTexture::bake(16, 16, checker);
+    assert_eq!((t.canvas.width, t.canvas.height), (16, 16));
+    // Texels are baked at their centers.
+    let t2 = ImageTexture::bake(2, 1, |u, v| ColorRgb::new(u, v, 0.0));
+    assert_eq!(t2.sample(0.0, 0.0), ColorRgb::new(0.25, 0.5, 0.0));
+    assert_eq!(t2.sample(0.99, 0.99), ColorRgb::new(0.75, 0.5, 0.0));
+    for (u, v) in [(0.1, 0.1), (0.3, 0.1), (0.6, 0.9), (0.9, 0.6)] {
+        assert_eq!(t.sample(u, v), checker(u, v));
+    }
+}
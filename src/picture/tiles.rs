@@ -0,0 +1,100 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Orders in which the tiles -buckets- of an image are rendered.
+*/
+
+// Tiles Unit Tests
+#[cfg(test)]
+mod tests;
+
+/// Selects the order in which the tiles of an image are visited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TileOrder {
+    /// Row after row, from left to right.
+    #[default]
+    Scanline,
+    /// Square spiral from the center tile outwards, so the middle of the image comes first.
+    Spiral,
+    /// Hilbert curve, so consecutive tiles are neighbours and stay cache friendly. On grids
+    /// that are not a power-of-two square the curve is clipped, and it jumps over the part
+    /// left outside the grid.
+    Hilbert,
+}
+
+/// Returns the (column, row) coordinates of every tile of a grid of 'columns' by 'rows'
+/// tiles, in the given order. Every tile appears exactly once.
+pub fn tile_order(columns: usize, rows: usize, order: TileOrder) -> Vec<(usize, usize)> {
+    match order {
+        TileOrder::Scanline => (0..rows)
+            .flat_map(|row| (0..columns).map(move |col| (col, row)))
+            .collect(),
+        TileOrder::Spiral => spiral_order(columns, rows),
+        TileOrder::Hilbert => hilbert_order(columns, rows),
+    }
+}
+
+// Walks a square spiral -right, down, left, up, with growing legs- from the center tile,
+// keeping the tiles that fall inside the grid.
+fn spiral_order(columns: usize, rows: usize) -> Vec<(usize, usize)> {
+    let total = columns * rows;
+    let mut tiles = Vec::with_capacity(total);
+    let (mut col, mut row) = (((columns as i64) - 1) / 2, ((rows as i64) - 1) / 2);
+    let directions = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+    let mut leg = 1;
+    let mut dir = 0;
+    while tiles.len() < total {
+        // Every leg length is walked twice before it grows.
+        for _ in 0..2 {
+            for _ in 0..leg {
+                if (0..columns as i64).contains(&col) && (0..rows as i64).contains(&row) {
+                    tiles.push((col as usize, row as usize));
+                }
+                col += directions[dir].0;
+                row += directions[dir].1;
+            }
+            dir = (dir + 1) % 4;
+        }
+        leg += 1;
+    }
+    tiles.truncate(total);
+    tiles
+}
+
+// Visits a square Hilbert curve covering the grid, keeping the tiles inside it.
+fn hilbert_order(columns: usize, rows: usize) -> Vec<(usize, usize)> {
+    let side = columns.max(rows).next_power_of_two();
+    (0..side * side)
+        .map(|d| hilbert_point(side, d))
+        .filter(|&(col, row)| col < columns && row < rows)
+        .collect()
+}
+
+// Converts a distance 'd' along a Hilbert curve of 'side' x 'side' cells to its cell.
+fn hilbert_point(side: usize, d: usize) -> (usize, usize) {
+    let (mut x, mut y) = (0, 0);
+    let mut t = d;
+    let mut s = 1;
+    while s < side {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (x, y)
+}
@@ -0,0 +1,56 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the tile orders.
+
+use super::*;
+use std::collections::HashSet;
+
+#[test]
+// Every order visits every tile exactly once
+fn ut_tiles_order_is_permutation() {
+    for order in [TileOrder::Scanline, TileOrder::Spiral, TileOrder::Hilbert] {
+        for (columns, rows) in [(1, 1), (4, 4), (5, 3), (2, 7)] {
+            let tiles = tile_order(columns, rows, order);
+            let unique: HashSet<_> = tiles.iter().collect();
+            assert_eq!(tiles.len(), columns * rows);
+            assert_eq!(unique.len(), columns * rows);
+        }
+    }
+    assert_eq!(tile_order(0, 3, TileOrder::Spiral), vec![]);
+}
+
+#[test]
+// Scanline goes row by row, Spiral starts at the center tile
+fn ut_tiles_scanline_and_spiral() {
+    assert_eq!(
+        tile_order(2, 2, TileOrder::Scanline),
+        vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+    );
+    let spiral = tile_order(5, 3, TileOrder::Spiral);
+    assert_eq!(&spiral[..3], &[(2, 1), (3, 1), (3, 2)]);
+}
+
+#[test]
+// Consecutive Hilbert tiles are neighbours on a power-of-two square grid, and the clipped
+// curve jumps over the tiles left outside other grids
+fn ut_tiles_hilbert_adjacent() {
+    let distances = |tiles: Vec<(usize, usize)>| -> Vec<usize> {
+        tiles
+            .windows(2)
+            .map(|pair| pair[0].0.abs_diff(pair[1].0) + pair[0].1.abs_diff(pair[1].1))
+            .collect()
+    };
+    let tiles = tile_order(8, 8, TileOrder::Hilbert);
+    assert_eq!(tiles[0], (0, 0));
+    assert!(distances(tiles).iter().all(|&d| d == 1));
+
+    let clipped = distances(tile_order(5, 3, TileOrder::Hilbert));
+    assert_eq!(clipped.iter().filter(|&&d| d > 1).count(), 2);
+    assert_eq!(clipped.iter().max(), Some(&4));
+}
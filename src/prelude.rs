@@ -0,0 +1,27 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Re-exports of the most used types and traits, so a single `use ruxel::prelude::*;`
+brings the constructors and operations of the core types into scope.
+*/
+
+pub use crate::geometry::intersection::{Intersection, Intxn};
+pub use crate::geometry::matrix::{Matrix3, Matrix4, Matrix4Ops};
+pub use crate::geometry::ray::{Ray, Rays};
+pub use crate::geometry::scalar::Scalar;
+pub use crate::geometry::vector::{Point, Point3, Tuple, Vector, Vector3};
+pub use crate::lights::{DirectionalLight, Light};
+pub use crate::picture::canvas::{Canvas, Pixel};
+pub use crate::picture::colors::{ColorInit, ColorRgb};
+pub use crate::shapes::{sphere::Sphere, Shape, ShapeId};
+pub use crate::world::World;
+
+// Unit tests for the prelude
+#[cfg(test)]
+mod tests;
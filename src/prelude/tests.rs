@@ -0,0 +1,28 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for the prelude.
+
+use crate::prelude::*;
+
+#[test]
+// The prelude alone is enough to build a scene and trace a ray through it
+fn ut_prelude_imports() {
+    let mut world = World::new();
+    let mut sphere = Sphere::new(ShapeId(1));
+    sphere.set_transform(Matrix4::from_scale(2.0, 2.0, 2.0));
+    let id = world.add(sphere);
+
+    let ray = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::z_coord(1.0));
+    let xs = Sphere::intersect(*world.get(id).unwrap(), ray);
+    assert_eq!(xs[0].t, 3.0);
+
+    let mut canvas = Canvas::new(1, 1);
+    canvas.write_pixel(Pixel::new(0, 0, ColorRgb::red()));
+    assert_eq!(canvas.read_pixel(0, 0), ColorRgb::red());
+}
@@ -9,9 +9,8 @@
 /**
 The Shapes module implements the functionality for Core shapes like Circle, Cylinder, Cube, and for External shapes from import of *.OBJ files or other formats
 */
-use num::{Num, NumCast};
-
 use crate::geometry::matrix::*;
+use crate::geometry::scalar::Scalar;
 use crate::geometry::{ray::Ray, vector::Point3};
 
 use crate::geometry::intersection::{Intersection, Intxn, IntxnVec};
@@ -23,30 +22,61 @@ pub mod sphere;
 /// Provides the data structure and implementation to import External shapes
 pub mod external;
 
+/// Provides sphere-tracing of Shapes through their distance functions
+pub mod sdf;
+
+/// Stable identifier of a Shape used to look it up once it is part of a World.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ShapeId(pub u32);
+
+impl Display for ShapeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+impl From<u32> for ShapeId {
+    fn from(id: u32) -> Self {
+        ShapeId(id)
+    }
+}
+
 /// Trait representing a Shape.
 pub trait Shape<P>
 where
-    P: Num + Copy + Display,
+    P: Scalar,
 {
+    /// Returns the point on the surface of a Shape closest to 'point'.
+    fn closest_point(&self, point: Point3<P>) -> Point3<P>;
+
+    /// Returns the signed distance from 'point' to the surface of a Shape,
+    /// negative when 'point' lies inside the Shape.
+    /// The magnitude may be a lower bound of the true distance, but never exceeds it.
+    fn distance_to(&self, point: Point3<P>) -> P;
+
     /// Returns the 'id' of a Shape.
-    fn get_id(&self) -> i32;
+    fn get_id(&self) -> ShapeId;
 
     /// Returns the 'name' of a Shape.
-    fn get_name<'a>(&'a self) -> &'a str;
+    fn get_name(&self) -> &str;
 
     /// Returns the origin coordinates (Point3) of a Shape.
     fn get_origin(&self) -> Point3<P>;
 
-    /// Returns the origin coordinates (Point3) of a Shape.
+    /// Returns the transformation matrix of a Shape.
     fn get_transform(&self) -> Matrix4<P>;
 
+    /// Returns the cached inverse of the transformation matrix of a Shape, or None if
+    /// the transformation is singular.
+    fn get_inverse_transform(&self) -> Option<Matrix4<P>>;
+
     /// Returns a collection of 't' values ('xs') where the Ray intersects a Shape.
     fn intersect<S>(shape: S, ray: Ray<P>) -> IntxnVec<P, S>
     where
         S: Shape<P> + Copy;
 
     /// Creates and returns a new shape.
-    fn new(id: i32) -> Self;
+    fn new(id: ShapeId) -> Self;
 
     /// Set the transformation of a shape.
     fn set_transform(&mut self, mat: Matrix4<P>);
@@ -0,0 +1,110 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+ Sphere-tracing (ray marching) of Shapes through their signed distance functions,
+ including a heatmap debug render that colors pixels by iteration count.
+*/
+use super::*;
+
+use crate::geometry::ray::Rays;
+use crate::picture::canvas::{Canvas, Pixel};
+use crate::picture::colors::{ColorInit, ColorRgb};
+
+// Unit tests for ray marching
+#[cfg(test)]
+mod tests;
+
+/// Settings that control the marching of a Ray through a distance field.
+#[derive(Clone, Copy, Debug)]
+pub struct MarchSettings<P> {
+    /// Maximum number of steps before giving up.
+    pub max_steps: usize,
+    /// Distance under which the Ray is considered to have hit the surface.
+    pub epsilon: P,
+    /// Distance along the Ray after which it is considered to have missed.
+    pub max_distance: P,
+}
+
+/// Outcome of marching a Ray through a distance field.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct March<P> {
+    /// Distance 't' along the Ray of the hit, if any.
+    pub t: Option<P>,
+    /// Number of steps taken.
+    pub steps: usize,
+}
+
+/// Marches a Ray through the distance field of a Shape.
+/// The Ray direction is expected to be normalized.
+#[cfg_attr(feature = "profiling", profiling::function)]
+pub fn march<P, S>(shape: &S, ray: Ray<P>, settings: MarchSettings<P>) -> March<P>
+where
+    P: Scalar,
+    S: Shape<P>,
+{
+    let mut t: P = num::zero();
+    for step in 1..=settings.max_steps {
+        let distance = shape.distance_to(Ray::position(ray, t));
+        if distance < settings.epsilon {
+            return March {
+                t: Some(t),
+                steps: step,
+            };
+        }
+        t = t + distance;
+        if t > settings.max_distance {
+            return March {
+                t: None,
+                steps: step,
+            };
+        }
+    }
+    March {
+        t: None,
+        steps: settings.max_steps,
+    }
+}
+
+/// Maps an iteration count to a blue (few steps) to red (max steps) color ramp.
+pub fn heatmap(steps: usize, max_steps: usize) -> ColorRgb {
+    let x = if max_steps == 0 {
+        1.0
+    } else {
+        (steps as f64 / max_steps as f64).clamp(0.0, 1.0)
+    };
+    if x < 0.5 {
+        ColorRgb::new(0.0, 2.0 * x, 1.0 - 2.0 * x)
+    } else {
+        ColorRgb::new(2.0 * x - 1.0, 2.0 - 2.0 * x, 0.0)
+    }
+}
+
+/// Renders the iteration-count heatmap of a Shape into a Canvas, using 'ray_for' to
+/// generate the Ray of every pixel from its (x, y) coordinates.
+pub fn render_heatmap<P, S, F>(
+    shape: &S,
+    width: usize,
+    height: usize,
+    settings: MarchSettings<P>,
+    ray_for: F,
+) -> Canvas
+where
+    P: Scalar,
+    S: Shape<P>,
+    F: Fn(usize, usize) -> Ray<P>,
+{
+    let mut canvas = Canvas::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let m = march(shape, ray_for(x, y), settings);
+            canvas.write_pixel(Pixel::new(x, y, heatmap(m.steps, settings.max_steps)));
+        }
+    }
+    canvas
+}
@@ -0,0 +1,50 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for ray marching.
+
+use super::*;
+use crate::geometry::vector::*;
+use crate::shapes::sphere::Sphere;
+
+const SETTINGS: MarchSettings<f64> = MarchSettings {
+    max_steps: 64,
+    epsilon: 0.0001,
+    max_distance: 100.0,
+};
+
+#[test]
+// Marching a ray towards a sphere
+fn ut_sdf_march_hit_and_miss() {
+    let s = Sphere::new(ShapeId(1));
+    let r = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::z_coord(1.0));
+    let m = march(&s, r, SETTINGS);
+    assert_eq!(m.t, Some(4.0));
+    assert_eq!(m.steps, 2);
+
+    let r = Ray::new(Point3::new(0.0, 2.0, -5.0), Vector3::z_coord(1.0));
+    let m = march(&s, r, SETTINGS);
+    assert_eq!(m.t, None);
+    assert!(m.steps < SETTINGS.max_steps);
+}
+
+#[test]
+// Heatmap ramp endpoints and rendering
+fn ut_sdf_heatmap_render() {
+    assert_eq!(heatmap(0, 10), ColorRgb::blue());
+    assert_eq!(heatmap(5, 10), ColorRgb::green());
+    assert_eq!(heatmap(10, 10), ColorRgb::red());
+
+    let s = Sphere::new(ShapeId(1));
+    let canvas = render_heatmap(&s, 5, 5, SETTINGS, |x, y| {
+        let origin = Point3::new(x as f64 - 2.0, y as f64 - 2.0, -5.0);
+        Ray::new(origin, Vector3::z_coord(1.0))
+    });
+    assert_eq!(canvas.data[2][2], heatmap(2, SETTINGS.max_steps));
+    assert_ne!(canvas.data[0][0], canvas.data[2][2]);
+}
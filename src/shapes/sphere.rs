@@ -13,17 +13,13 @@ use crate::geometry::ray::*;
 use crate::intersections;
 use std::fmt::Display;
 
-use num::{
-    integer::{sqrt, Roots},
-    Num, NumCast, ToPrimitive,
-};
-use std::ops::Neg;
+use num::Float;
 
 /**
  Data structures representing the core hapes Sphere
 */
 // Bring Vector3, Point3 and Ray types into scope
-use crate::geometry::{ray::Ray, vector::*};
+use crate::geometry::{ray::Ray, scalar::Scalar, transform::Transform, vector::*};
 
 // Unit tests for Sphere
 #[cfg(test)]
@@ -32,21 +28,142 @@ mod tests;
 /// Representation of a 3D sphere
 #[derive(Clone, Copy, Debug)]
 pub struct Sphere<'a, P> {
-    /// id of the Sphere.
-    pub id: i32,
+    // id of the Sphere, replaced by World::add so it is unique in its World.
+    pub(crate) id: ShapeId,
     /// Name of the Spher.
     pub name: &'a str,
     /// Origin or 'center' of the Sphere.
     pub origin: Point3<P>,
-    /// Transformation matrix of the Sphere.
-    pub transform: Matrix4<P>,
+    /// Transformation of the Sphere, with its cached inverse.
+    pub transform: Transform<P>,
 }
 
+// Returns the 't' values where a Ray, already transformed to object space, intersects the
+// unit sphere.
+fn intersect_unit_sphere<P: Scalar + Float>(ray: Ray<P>) -> Option<(P, P)> {
+    // Unit sphere at the origin of object space.
+    let two = P::one() + P::one();
+    let sphere_to_ray = ray.origin - Point3::zero();
+    let a = Vector3::dot(ray.direction, ray.direction);
+    let b = two * Vector3::dot(ray.direction, sphere_to_ray);
+    let c = Vector3::dot(sphere_to_ray, sphere_to_ray) - P::one();
+
+    let discriminant = b * b - two * two * a * c;
+    if discriminant < P::zero() || a == P::zero() {
+        return None;
+    }
+    let t1 = (-b - discriminant.sqrt()) / (two * a);
+    let t2 = (-b + discriminant.sqrt()) / (two * a);
+    Some((t1, t2))
+}
+
+// Returns the smallest and largest factors by which the linear transformation 'm' scales
+// lengths: its singular values, from the eigenvalues of the symmetric matrix m^T * m.
+fn scale_range<P: Scalar + Float>(m: Matrix3<P>) -> (P, P) {
+    let a = (m.transpose() * m).data();
+    let (two, three) = (P::one() + P::one(), P::one() + P::one() + P::one());
+    let q = (a[0][0] + a[1][1] + a[2][2]) / three;
+    let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+    let diagonal = (a[0][0] - q).powi(2) + (a[1][1] - q).powi(2) + (a[2][2] - q).powi(2);
+    let p = ((diagonal + two * off_diagonal) / (two * three)).sqrt();
+    if p == P::zero() {
+        // Multiple of the identity: the scaling is uniform.
+        return (q.sqrt(), q.sqrt());
+    }
+
+    let mut b = Matrix3::from_data(a);
+    for i in 0..3 {
+        b[(i, i)] = b[(i, i)] - q;
+    }
+    let r = (b.determinant() / (two * p.powi(3)))
+        .max(-P::one())
+        .min(P::one());
+    let phi = r.acos() / three;
+    let third_turn = P::from(2.0 * std::f64::consts::PI / 3.0).unwrap();
+    let largest = q + two * p * phi.cos();
+    let smallest = q + two * p * (phi + third_turn).cos();
+    (smallest.max(P::zero()).sqrt(), largest.sqrt())
+}
+
+impl<'a, P> Sphere<'a, P>
+where
+    P: Scalar + Float,
+{
+    /// Returns the intersections of a Ray with the Sphere, referencing the Sphere
+    /// instead of copying it into every intersection.
+    pub fn intersect_ref<'s>(&'s self, ray: Ray<P>) -> IntxnVec<P, &'s Sphere<'a, P>> {
+        // A singular transform flattens the sphere, which a Ray then never intersects.
+        match self
+            .transform
+            .to_object_space(ray)
+            .and_then(intersect_unit_sphere)
+        {
+            None => vec![],
+            Some((t1, t2)) => intersections![
+                Intxn {
+                    t: t1,
+                    object: self
+                },
+                Intxn {
+                    t: t2,
+                    object: self
+                }
+            ],
+        }
+    }
+}
+
+// Spheres compute in their own floating point scalar type, without converting to f64.
 impl<'a, P> Shape<P> for Sphere<'a, P>
 where
-    P: Num + NumCast + Copy + PartialEq + PartialOrd + Neg + Neg<Output = P> + Display,
+    P: Scalar + Float,
 {
-    fn get_id(&self) -> i32 {
+    // Exact for spheres with uniform scaling; for non-uniform scaling the point is the
+    // radial projection in object space, which is a close approximation.
+    // A singular transform collapses the sphere, and the image of its top is returned.
+    fn closest_point(&self, point: Point3<P>) -> Point3<P> {
+        let mut radial = match self.transform.inverse() {
+            Some(inverse) => inverse * point - Point3::zero(),
+            None => Vector3::zero(),
+        };
+        let length = Vector3::dot(radial, radial).sqrt();
+        if length < P::EPSILON {
+            radial = Vector3::y_coord(P::one());
+        } else {
+            radial = radial / length;
+        }
+        self.transform.matrix() * (Point3::zero() + radial)
+    }
+
+    // Exact for spheres with uniform scaling. Under non-uniform scaling the object space
+    // distance is scaled by the smallest scale factor of the transform, a bound that never
+    // overestimates the true distance, so it stays a valid signed distance for sdf::march.
+    fn distance_to(&self, point: Point3<P>) -> P {
+        // Nothing lies inside a sphere collapsed by a singular transform.
+        let inverse = match self.transform.inverse() {
+            Some(inverse) => inverse,
+            None => {
+                let to_surface = point - self.closest_point(point);
+                return Vector3::dot(to_surface, to_surface).sqrt();
+            }
+        };
+        let radial = inverse * point - Point3::zero();
+        let object_distance = Vector3::dot(radial, radial).sqrt() - P::one();
+
+        let (min_scale, max_scale) = scale_range(self.transform.matrix().upper_left());
+        if max_scale - min_scale > P::EPSILON * max_scale {
+            return object_distance * min_scale;
+        }
+        let to_surface = point - self.closest_point(point);
+        let distance = Vector3::dot(to_surface, to_surface).sqrt();
+        if object_distance < P::zero() {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    fn get_id(&self) -> ShapeId {
         self.id
     }
 
@@ -59,49 +176,42 @@ where
     }
 
     fn get_transform(&self) -> Matrix4<P> {
-        self.transform
+        self.transform.matrix()
     }
 
+    fn get_inverse_transform(&self) -> Option<Matrix4<P>> {
+        self.transform.inverse()
+    }
+
+    #[cfg_attr(feature = "profiling", profiling::function)]
     fn intersect<S>(shape: S, ray: Ray<P>) -> IntxnVec<P, S>
     where
         S: Shape<P> + Copy,
         P: Display,
     {
-        let ray = Ray::transform(
-            ray.ray_to_f64(),
-            shape.get_transform().mat_to_f64().inverse(),
-        );
-
-        let sphere_to_ray = ray.origin - Point3::zero();
-        let a = Vector3::dot(ray.direction, ray.direction);
-        let b = 2.0 * Vector3::dot(ray.direction, sphere_to_ray);
-        let c = Vector3::dot(sphere_to_ray, sphere_to_ray) - 1.0;
-
-        let discriminant: f64 = b * b - (4.0 * a * c);
-
-        if discriminant < num::zero() {
-            return vec![];
-        } else {
-            let t1 = P::from((-b - discriminant.sqrt()) / (2.0 * a)).unwrap();
-            let t2 = P::from((-b + discriminant.sqrt()) / (2.0 * a)).unwrap();
-
-            let i1 = Intxn::intersection(t1, shape);
-            let i2 = Intxn::intersection(t2, shape);
-            let xs = intersections![i1, i2];
-            return xs;
+        match shape
+            .get_inverse_transform()
+            .and_then(|inverse| intersect_unit_sphere(Ray::transform(ray, inverse)))
+        {
+            None => vec![],
+            Some((t1, t2)) => {
+                let i1 = Intxn::intersection(t1, shape);
+                let i2 = Intxn::intersection(t2, shape);
+                intersections![i1, i2]
+            }
         }
     }
 
-    fn new(id: i32) -> Sphere<'a, P> {
+    fn new(id: ShapeId) -> Sphere<'a, P> {
         Sphere {
             name: "sphere",
             id,
             origin: Point3::zero(),
-            transform: Matrix4::identity(),
+            transform: Transform::default(),
         }
     }
 
     fn set_transform(&mut self, mat: Matrix4<P>) {
-        self.transform = mat;
+        self.transform = Transform::new(mat);
     }
 }
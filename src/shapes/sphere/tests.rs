@@ -12,13 +12,15 @@ use super::*;
 use crate::geometry::{
     matrix::{Matrix4, Matrix4Ops},
     ray::*,
+    EPSILON,
 };
+use std::f64::consts::PI;
 
 #[test]
 // Intersects at two points
 fn ut_sphere_ray_intersect_2p() {
     let r = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
-    let s = Sphere::new(1);
+    let s = Sphere::new(ShapeId(1));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, 4.0);
@@ -29,7 +31,7 @@ fn ut_sphere_ray_intersect_2p() {
 // Intersects at a tangent
 fn ut_sphere_ray_intersect_tangent() {
     let r = Ray::new(Point3::new(0.0, 1.0, -5.0), Vector3::z_coord(1.0));
-    let s = Sphere::new(2);
+    let s = Sphere::new(ShapeId(2));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, 5.0);
@@ -40,7 +42,7 @@ fn ut_sphere_ray_intersect_tangent() {
 // Ray misses a sphere.
 fn ut_sphere_misses_ray() {
     let r = Ray::new(Point3::new(0.0, 2.0, -5.0), Vector3::z_coord(1.0));
-    let s = Sphere::new(3);
+    let s = Sphere::new(ShapeId(3));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 0);
 }
@@ -49,7 +51,7 @@ fn ut_sphere_misses_ray() {
 // Ray originates inside sphere.
 fn ut_sphere_ray_inside_sphere() {
     let r = Ray::new(Point3::zero(), Vector3::z_coord(1.0));
-    let s = Sphere::new(4);
+    let s = Sphere::new(ShapeId(4));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, -1.0);
@@ -60,7 +62,7 @@ fn ut_sphere_ray_inside_sphere() {
 // Ray originates behind sphere.
 fn ut_sphere_ray_behind_sphere() {
     let r = Ray::new(Point3::z_coord(5.0), Vector3::z_coord(1.0));
-    let s = Sphere::new(5);
+    let s = Sphere::new(ShapeId(5));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, -6.0);
@@ -71,7 +73,7 @@ fn ut_sphere_ray_behind_sphere() {
 // Intersect sets object
 fn ut_sphere_instersect_object() {
     let r = Ray::new(Point3::z_coord(-5.0), Vector3::z_coord(1.0));
-    let s = Sphere::new(5);
+    let s = Sphere::new(ShapeId(5));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].object.get_name(), s.get_name());
@@ -81,25 +83,26 @@ fn ut_sphere_instersect_object() {
 #[test]
 // Sphere default transformation.
 fn ut_sphere_default_transform() {
-    let s: Sphere<f64> = Sphere::new(1);
-    assert_eq!(s.transform, Matrix4::identity());
+    let s: Sphere<f64> = Sphere::new(ShapeId(1));
+    assert_eq!(s.transform.matrix(), Matrix4::<f64>::IDENTITY);
 }
 
 #[test]
 // Sphere change transformation.
 fn ut_sphere_change_transform() {
-    let mut s = Sphere::new(1);
-    let t = Matrix4::identity().translate(2, 3, 4);
+    let mut s = Sphere::new(ShapeId(1));
+    let t = Matrix4::from_translation(2.0, 3.0, 4.0);
     s.set_transform(t);
-    assert_eq!(s.transform, t);
+    assert_eq!(s.transform.matrix(), t);
+    assert_eq!(s.get_inverse_transform(), Some(t.inverse()));
 }
 
 #[test]
 // Scaled sphere intersecting with a Ray
 fn ut_sphere_intersect_scaled() {
     let r = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
-    let mut s = Sphere::new(1);
-    s.set_transform(Matrix4::identity().scale(2.0, 2.0, 2.0));
+    let mut s = Sphere::new(ShapeId(1));
+    s.set_transform(Matrix4::from_scale(2.0, 2.0, 2.0));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, 3.0);
@@ -107,23 +110,101 @@ fn ut_sphere_intersect_scaled() {
 }
 
 #[test]
-// Scaled sphere intersecting with a Ray
-fn ut_sphere_intersect_scaled_int() {
-    let r = Ray::new(Point3::new(0, 0, -5), Vector3::new(0, 0, 1));
-    let mut s = Sphere::new(1);
-    s.set_transform(Matrix4::identity().scale(2, 2, 2));
+// Scaled f32 sphere intersecting with a Ray, without converting to f64
+fn ut_sphere_intersect_scaled_f32() {
+    let r = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+    let mut s = Sphere::new(ShapeId(1));
+    s.set_transform(Matrix4::from_scale(2.0f32, 2.0, 2.0));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 2);
-    assert_eq!(xs[0].t, 3);
-    assert_eq!(xs[1].t, 7);
+    assert_eq!(xs[0].t, 3.0);
+    assert_eq!(xs[1].t, 7.0);
 }
 
 #[test]
 // Translated sphere intersecting with a Ray
 fn ut_sphere_intersect_translated() {
-    let r = Ray::new(Point3::z_coord(-5), Vector3::forward());
-    let mut s = Sphere::new(1);
-    s.set_transform(Matrix4::identity().translate(5, 0, 0));
+    let r = Ray::new(Point3::z_coord(-5.0), Vector3::z_coord(1.0));
+    let mut s = Sphere::new(ShapeId(1));
+    s.set_transform(Matrix4::from_translation(5.0, 0.0, 0.0));
     let xs = Sphere::intersect(s, r);
     assert_eq!(xs.len(), 0);
 }
+
+#[test]
+// Closest point and signed distance to a unit sphere
+fn ut_sphere_closest_point_and_distance() {
+    let s = Sphere::new(ShapeId(1));
+    let p = Point3::new(0.0, 3.0, 0.0);
+    assert_eq!(s.closest_point(p), Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(s.distance_to(p), 2.0);
+    assert_eq!(s.distance_to(Point3::new(0.0, 0.0, 0.5)), -0.5);
+    assert_eq!(s.distance_to(Point3::zero()), -1.0);
+}
+
+#[test]
+// Closest point and signed distance to a transformed sphere
+fn ut_sphere_closest_point_transformed() {
+    let mut s = Sphere::new(ShapeId(1));
+    s.set_transform(Matrix4::from_scale(2.0, 2.0, 2.0).translate(5.0, 0.0, 0.0));
+    let p = Point3::new(5.0, 0.0, -10.0);
+    assert_eq!(s.closest_point(p), Point3::new(5.0, 0.0, -2.0));
+    assert_eq!(s.distance_to(p), 8.0);
+}
+
+#[test]
+// Under non-uniform scaling the signed distance never overestimates the true distance
+fn ut_sphere_distance_non_uniform() {
+    let mut s = Sphere::new(ShapeId(1));
+    let m = Matrix4::from_scale(4.0, 1.0, 2.0)
+        .rotate_z(0.5)
+        .translate(1.0, 0.0, 0.0);
+    s.set_transform(m);
+    let (min_scale, max_scale) = scale_range(m.upper_left());
+    assert!((min_scale - 1.0).abs() < EPSILON && (max_scale - 4.0).abs() < EPSILON);
+
+    // Nearest of a dense sample of the surface, to approximate the true distance.
+    let surface: Vec<Point3<f64>> = (0..=200)
+        .flat_map(|i| (0..400).map(move |j| (i as f64 * PI / 200.0, j as f64 * PI / 200.0)))
+        .map(|(theta, phi)| {
+            m * Point3::new(
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            )
+        })
+        .collect();
+    for p in [
+        Point3::new(6.0, 3.0, 0.0),
+        Point3::new(-2.0, 1.5, 1.0),
+        Point3::new(1.0, 0.0, 1.5),
+        Point3::new(1.0, 0.0, -3.0),
+    ] {
+        let nearest = surface
+            .iter()
+            .map(|q| (p - *q).magnitude())
+            .fold(f64::INFINITY, f64::min);
+        let distance = s.distance_to(p);
+        assert!(
+            distance.abs() <= nearest + 1e-3,
+            "{} > {}",
+            distance,
+            nearest
+        );
+        assert!(distance.abs() > 0.0);
+    }
+    assert!(s.distance_to(Point3::new(1.0, 0.0, 1.5)) < 0.0);
+    // The object space distance, 0.5, scaled by the smallest scale factor.
+    assert!((s.distance_to(Point3::new(1.0, 0.0, -3.0)) - 0.5).abs() < EPSILON);
+}
+
+#[test]
+// A sphere with a zero scale is never intersected, and nothing lies inside it
+fn ut_sphere_singular_transform() {
+    let r = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+    let mut s = Sphere::new(ShapeId(1));
+    s.set_transform(Matrix4::from_scale(1.0, 0.0, 1.0));
+    assert_eq!(Sphere::intersect(s, r).len(), 0);
+    assert_eq!(s.closest_point(Point3::new(0.0, 3.0, 0.0)), Point3::zero());
+    assert_eq!(s.distance_to(Point3::new(0.0, 3.0, 0.0)), 3.0);
+}
@@ -0,0 +1,121 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Debug-mode guards that detect NaN and infinite values where they are created,
+instead of letting them propagate silently into black or white pixels.
+In release builds the guards are a no-op.
+*/
+use num::{Float, NumCast};
+use std::fmt::Display;
+
+use crate::geometry::vector::{Point3, Vector3};
+use crate::picture::colors::ColorRgb;
+use crate::shapes::ShapeId;
+
+// Unit tests for validation guards
+#[cfg(test)]
+mod tests;
+
+/// Describes where a value was created, to be reported when it is invalid.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Origin {
+    /// Shape that produced the value, if known.
+    pub shape: Option<ShapeId>,
+    /// Pixel being computed, if known.
+    pub pixel: Option<(usize, usize)>,
+}
+
+impl Origin {
+    /// Returns an Origin for a value produced by a Shape.
+    pub fn shape(id: ShapeId) -> Self {
+        Origin {
+            shape: Some(id),
+            pixel: None,
+        }
+    }
+
+    /// Returns an Origin for a value computed for a pixel.
+    pub fn pixel(x: usize, y: usize) -> Self {
+        Origin {
+            shape: None,
+            pixel: Some((x, y)),
+        }
+    }
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shape = match self.shape {
+            Some(id) => format!("{}", id),
+            None => "unknown".to_string(),
+        };
+        let pixel = match self.pixel {
+            Some((x, y)) => format!("[x:{}, y:{}]", x, y),
+            None => "unknown".to_string(),
+        };
+        write!(f, "shape: {}\tpixel: {}", shape, pixel)
+    }
+}
+
+/// Returns true if every component of the color is finite.
+pub fn is_finite_color(color: ColorRgb) -> bool {
+    color.r.is_finite() && color.g.is_finite() && color.b.is_finite()
+}
+
+/// Returns true if every component of the values is finite.
+pub fn is_finite<P>(values: &[P]) -> bool
+where
+    P: NumCast + Copy,
+{
+    values
+        .iter()
+        .all(|v| v.to_f64().map_or(false, |v| v.is_finite()))
+}
+
+/// Returns the color unchanged; panics in debug builds if it contains NaN or infinite values.
+pub fn validate_color(color: ColorRgb, origin: Origin) -> ColorRgb {
+    if cfg!(debug_assertions) && !is_finite_color(color) {
+        panic!("Non-finite color {} - {}", color, origin);
+    }
+    color
+}
+
+/// Returns the vector unchanged; panics in debug builds if it contains NaN or infinite values.
+pub fn validate_vector<P>(vector: Vector3<P>, origin: Origin) -> Vector3<P>
+where
+    P: NumCast + Copy + Display,
+{
+    if cfg!(debug_assertions) && !is_finite(&[vector.x, vector.y, vector.z, vector.w]) {
+        panic!("Non-finite vector {} - {}", vector, origin);
+    }
+    vector
+}
+
+/// Returns the point unchanged; panics in debug builds if it contains NaN or infinite values.
+pub fn validate_point<P>(point: Point3<P>, origin: Origin) -> Point3<P>
+where
+    P: NumCast + Copy + Display,
+{
+    if cfg!(debug_assertions) && !is_finite(&[point.x, point.y, point.z, point.w]) {
+        panic!("Non-finite point {} - {}", point, origin);
+    }
+    point
+}
+
+/// Returns the value unchanged; panics in debug builds if it is NaN.
+/// Infinite values are accepted, e.g. the depth of pixels where nothing was hit.
+pub fn validate_value<P>(value: P, origin: Origin) -> P
+where
+    P: Float + Display,
+{
+    if cfg!(debug_assertions) && value.is_nan() {
+        panic!("NaN value {} - {}", value, origin);
+    }
+    value
+}
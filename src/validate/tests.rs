@@ -0,0 +1,58 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for validation guards.
+
+use super::*;
+use crate::geometry::vector::*;
+use crate::picture::canvas::{Canvas, Pixel};
+use crate::picture::colors::ColorInit;
+
+#[test]
+// Finite values go through unchanged
+fn ut_validate_finite_values() {
+    let c = ColorRgb::new(0.5, 2.0, -1.0);
+    assert_eq!(validate_color(c, Origin::default()), c);
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(validate_vector(v, Origin::shape(ShapeId(1))), v);
+    let p = Point3::new(1, 2, 3);
+    assert_eq!(validate_point(p, Origin::pixel(0, 0)), p);
+    assert!(!is_finite(&[1.0, f64::NAN]));
+    assert!(!is_finite_color(ColorRgb::new(0.0, f64::INFINITY, 0.0)));
+    assert_eq!(
+        validate_value(f32::INFINITY, Origin::default()),
+        f32::INFINITY
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "shape: #3\tpixel: unknown")]
+// NaN normals are reported with the shape that produced them
+fn ut_validate_nan_vector() {
+    let mut v: Vector3<f64> = Vector3::zero();
+    validate_vector(v.normalized(), Origin::shape(ShapeId(3)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "pixel: [x:1, y:2]")]
+// NaN radiance is reported with the pixel being written
+fn ut_validate_nan_pixel() {
+    let mut canvas = Canvas::new(3, 3);
+    canvas.write_pixel(Pixel::new(1, 2, ColorRgb::new(f64::NAN, 0.0, 0.0)));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "NaN value NaN - shape: unknown\tpixel: [x:0, y:1]")]
+// NaN depth values are reported with the pixel being written
+fn ut_validate_nan_depth() {
+    let mut depth: Canvas<f32> = Canvas::filled(2, 2, 0.0);
+    depth.write_pixel(Pixel::new(0, 1, f32::NAN));
+}
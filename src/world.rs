@@ -0,0 +1,138 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+/*!
+Data structures and methods for the World type.
+*/
+use num::Float;
+use std::fmt::Display;
+
+use crate::geometry::intersection::{merge_intersections, IntxnVec};
+use crate::geometry::ray::Ray;
+use crate::geometry::scalar::Scalar;
+
+use crate::shapes::{sphere::Sphere, Shape, ShapeId};
+
+// Unit tests for World
+#[cfg(test)]
+mod tests;
+
+/// Type representing a World: the collection of Shapes that make up a scene.
+#[derive(Clone, Debug, Default)]
+pub struct World<'a, P> {
+    // Shapes contained in the World, only added through World::add.
+    objects: Vec<Sphere<'a, P>>,
+    // Next ShapeId allocated by World::add; ids are never reused.
+    next_id: u32,
+}
+
+/// Trait for the operations walking the contents of a World, e.g. exporters, validators,
+/// statistics or pickers, so they do not re-implement the traversal.
+/// Every method has an empty default implementation, so a visitor only implements the
+/// elements it is interested in.
+pub trait SceneVisitor<'a, P> {
+    /// Called once for every Shape of the World, in insertion order.
+    fn visit_shape(&mut self, _shape: &Sphere<'a, P>) {}
+}
+
+impl<'a, P> World<'a, P>
+where
+    P: Scalar + Float,
+{
+    /// Creates a new empty [`World`].
+    pub fn new() -> Self {
+        World {
+            objects: vec![],
+            next_id: 0,
+        }
+    }
+
+    /// Adds a Shape to the World and returns its [`ShapeId`].
+    /// The id is allocated by the World, replacing the id the Shape was created with, so
+    /// every Shape of the World has a unique id that stays valid for the World's lifetime.
+    pub fn add(&mut self, mut object: Sphere<'a, P>) -> ShapeId {
+        let id = ShapeId(self.next_id);
+        self.next_id += 1;
+        object.id = id;
+        self.objects.push(object);
+        id
+    }
+
+    /// Returns an iterator over the Shapes of the World, in insertion order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Sphere<'a, P>> {
+        self.objects.iter()
+    }
+
+    /// Returns the number of Shapes in the World.
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    /// Returns true if the World has no Shapes.
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// Returns a reference to the Shape with the given [`ShapeId`], if any.
+    pub fn get(&self, id: ShapeId) -> Option<&Sphere<'a, P>> {
+        self.objects.iter().find(|s| s.get_id() == id)
+    }
+
+    /// Returns a mutable reference to the Shape with the given [`ShapeId`], if any.
+    /// The id of the Shape cannot be changed through it.
+    pub fn get_mut(&mut self, id: ShapeId) -> Option<&mut Sphere<'a, P>> {
+        self.objects.iter_mut().find(|s| s.get_id() == id)
+    }
+
+    /// Returns a reference to the first Shape with the given name, if any.
+    pub fn get_by_name(&self, name: &str) -> Option<&Sphere<'a, P>> {
+        self.objects.iter().find(|s| s.get_name() == name)
+    }
+
+    /// Walks the contents of the World with a [`SceneVisitor`].
+    pub fn visit(&self, visitor: &mut impl SceneVisitor<'a, P>) {
+        for object in &self.objects {
+            visitor.visit_shape(object);
+        }
+    }
+
+    /// Returns the intersections of a Ray with every Shape of the World, sorted by
+    /// increasing 't'. Intersections reference the Shapes instead of copying them.
+    /// Intersections closer than [`EPSILON`](crate::geometry::EPSILON) are merged, see
+    /// [`World::intersect_with_epsilon`], so a returned 't' can differ from the exact
+    /// intersection by up to EPSILON.
+    pub fn intersect(&self, ray: Ray<P>) -> IntxnVec<P, &Sphere<'a, P>> {
+        self.intersect_with_epsilon(ray, P::EPSILON)
+    }
+
+    /// Returns the intersections of a Ray with every Shape of the World, sorted by
+    /// increasing 't', merging the intersections closer than 'epsilon' with
+    /// [`merge_intersections`]. Merged intersections take the lowest 't' of their group,
+    /// which moves their 't' by up to 'epsilon'.
+    pub fn intersect_with_epsilon(&self, ray: Ray<P>, epsilon: P) -> IntxnVec<P, &Sphere<'a, P>> {
+        let mut xs: IntxnVec<P, &Sphere<'a, P>> = self
+            .objects
+            .iter()
+            .flat_map(|object| object.intersect_ref(ray))
+            .collect();
+        merge_intersections(&mut xs, epsilon, |s| s.get_id());
+        xs
+    }
+
+    /// Returns a mutable reference to the first Shape with the given name, if any.
+    pub fn get_by_name_mut(&mut self, name: &str) -> Option<&mut Sphere<'a, P>> {
+        self.objects.iter_mut().find(|s| s.get_name() == name)
+    }
+}
+
+impl<'a, P> Display for World<'a, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("World - objects: {}", self.objects.len());
+        f.write_str(&s)
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2022 Rodrigo Santiago.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Unit tests for World types.
+
+use super::*;
+use crate::geometry::intersection::hit;
+use crate::geometry::matrix::{Matrix4, Matrix4Ops};
+use crate::geometry::ray::Rays;
+use crate::geometry::vector::*;
+
+#[test]
+// Lookup of shapes by id
+fn ut_world_get_by_id() {
+    let mut w: World<f64> = World::new();
+    let first = w.add(Sphere::new(ShapeId(7)));
+    // Ids are allocated by the World, so duplicates are impossible.
+    let second = w.add(Sphere::new(ShapeId(7)));
+    assert_ne!(first, second);
+    assert_eq!(w.get(first).unwrap().get_id(), first);
+    assert_eq!(w.get(second).unwrap().get_id(), second);
+    assert!(w.get(ShapeId(9)).is_none());
+    assert_eq!(w.len(), 2);
+    assert!(!w.is_empty() && World::<f64>::new().is_empty());
+    let ids: Vec<ShapeId> = w.iter().map(|s| s.get_id()).collect();
+    assert_eq!(ids, vec![first, second]);
+    println!("{}", w);
+}
+
+#[test]
+// Shapes can be tweaked after being added to the World
+fn ut_world_get_mut_and_by_name() {
+    let mut w = World::new();
+    let mut s = Sphere::new(ShapeId(1));
+    s.name = "ball";
+    let ball = w.add(s);
+    let other = w.add(Sphere::new(ShapeId(2)));
+
+    let t = Matrix4::from_translation(1.0, 2.0, 3.0);
+    w.get_mut(ball).unwrap().set_transform(t);
+    assert_eq!(w.get_by_name("ball").unwrap().get_transform(), t);
+
+    w.get_by_name_mut("sphere").unwrap().name = "other";
+    assert_eq!(w.get(other).unwrap().get_name(), "other");
+    assert!(w.get_by_name("sphere").is_none());
+}
+
+#[test]
+// World intersections are sorted and reference the intersected Shapes
+fn ut_world_intersect() {
+    let mut w = World::new();
+    let mut far = Sphere::new(ShapeId(1));
+    far.set_transform(Matrix4::from_translation(0.0, 0.0, 10.0));
+    w.add(far);
+    let near = w.add(Sphere::new(ShapeId(2)));
+
+    let ray = Ray::new(Point3::new(0.0, 0.0, -5.0), Vector3::z_coord(1.0));
+    let xs = w.intersect(ray);
+    let ts: Vec<f64> = xs.iter().map(|i| i.t).collect();
+    assert_eq!(ts, vec![4.0, 6.0, 14.0, 16.0]);
+    assert!(std::ptr::eq(xs[0].object, w.iter().nth(1).unwrap()));
+
+    let h = hit(xs).unwrap();
+    assert_eq!(h.object.get_id(), near);
+    // A Ray missing every Shape has no hit.
+    let miss = Ray::new(Point3::new(0.0, 5.0, 0.0), Vector3::y_coord(1.0));
+    assert!(hit(w.intersect(miss)).is_none());
+}
+
+#[test]
+// A visitor walks every Shape of the World
+fn ut_world_visit() {
+    #[derive(Default)]
+    struct Names(Vec<String>);
+
+    impl<'a> SceneVisitor<'a, f64> for Names {
+        fn visit_shape(&mut self, shape: &Sphere<'a, f64>) {
+            self.0
+                .push(format!("{}{}", shape.get_name(), shape.get_id()));
+        }
+    }
+
+    let mut w = World::new();
+    w.add(Sphere::new(ShapeId(1)));
+    w.add(Sphere::new(ShapeId(2)));
+    let mut names = Names::default();
+    w.visit(&mut names);
+    assert_eq!(names.0, vec!["sphere#0", "sphere#1"]);
+}